@@ -7,9 +7,8 @@
     let r = Some(46);
     let g = Some(115);
     let b = Some(82);
-    if let Some(encoded_image) = create_img(ciphertext, style, watermark, r, g, b, None, None, None) {
-      println!("Encoded image: {}", encoded_image);
-    } else {
-      println!("Image creation or encoding failed.");
+    match create_img(ciphertext, style, watermark, r, g, b, None, None, None) {
+      Ok(encoded_image) => println!("Encoded image: {}", encoded_image),
+      Err(error) => println!("Image creation or encoding failed: {}", error),
     }
   }
@@ -10,9 +10,8 @@
     let a = Some(0);
     let w = Some(32);
     let h = Some(32);
-    if let Some(encoded_image) = create_img(ciphertext, style, watermark, r, g, b, a, w, h) {
-      println!("Encoded image: {}", encoded_image);
-    } else {
-      println!("Image creation or encoding failed.");
+    match create_img(ciphertext, style, watermark, r, g, b, a, w, h) {
+      Ok(encoded_image) => println!("Encoded image: {}", encoded_image),
+      Err(error) => println!("Image creation or encoding failed: {}", error),
     }
   }
@@ -3,9 +3,8 @@
   fn main() {
     let encoded_image = "iVBORw0KGgoAAAANSUhEUgAAAGwAAABsCAYAAACPZlfNAAA3rklEQVR4nO3deWxcW34n9u9dat+ryFpY3JeiJGqhpLfoLXS3uttre2L3xJPxuB0HjoNkepAgHiQzCBIgSJwAGTjOJJ5BkHiWbJ7ECyaDAJN0HMNu9/L6vX79+kmiRFEiRZHFnSyyWFVk7XWrbr7n3kOyROm9fpjpf9jgARqtEsmqOudz7zm/haqnBAffNT0791Bqt+DpuYFSyo2ku4bmsg70FgBzGb7CHezmPoQjM4Kx517kMh24Fx9j72YAsUeDyI0r6Dyeh3PYiajnFvaDDxF8cITmyDiqmgdOVYFurMLwDqL9YB7m7V4E9DhaB1U0nq0AYyOIu3cAbxnlfAwlNYmwaw3G5iX09jqwGK0i0VJgrurojD9EalbF83oT3jf5+L2rqCWz8IWAWovfV7+K7aGniK1cw+7ABzAdExBDM57Dlb2B1ugWQo0+1NseNHJP4U31o7q9wb+PQTOXoO/2w3cURiVQRDO2Dn9ZQb0QQaCdxm6fHwllB2XnPlqOHiSLKeR8B/BWttCshaDGQmgq86g33oTb+z2ohhNeZRp5Zx3hj+egD4asuV1a0rAe0BB161jn2l/yOfl6V9Eq1LBqLCEZu4Zm04CSmEXKOYAV5y4C2QwOJupQAgPvmL7cPRRaLfgIVngJLItA4Q1sS7AJgu0QzLv0BLs3fOgl2M4JmBs9nmnkCBYmWH3kFWAPn8K8GUOIYA0B9nwVGBniawqwKg7zEWtSx2AJgj3tAsP4HFKPVDwjmP91HcZ7V7rA6kjVr2CTYL0E234BbAWe7DU0PhVsGc7dPngIViNY3QLTCBY6AUsR7FCC9RFsx2eDGQSDBfaUYK8T7PsE018JdoVgqwTrIdgqwa4E3Kj4rthgnSXEI8dgj5B2pvG8G8zf/7YZ2L+HfKOFAMHyL4GtIVR4DZsSbJJgWwTzLy9g+5oHCYJtnYB5Efdcxw7BogSrjrwCbG4R5o0IIgSrCbCVdWBoAH0WWB3FvD2pY7AUwea7wNTxeaQeq1ggWPC2jub7l1CLH4M1ka5fwjrBEgTbfAFsFb7sFGqfCpaFezcJF8EaBKvGBJiDYIETsDTBihKsn2BbEqxDsI4Ftkiw2wT7AcHUV4JdJdgKweIEWyHY1ZAXFc8lGwxL6Akdgz3GgDOFZ91gvvRbZujgHvZqLYQkWPwFsA1ECrewLsEuE2yDYMHsM2xOuXi1D2LjBMyPpOcqtgjW86CC8sjoy2DzSzCvhRAjWEWArW4CA2n0W2BNHOQD1qSOwdIEm+sC08efIvVExROChW/qqH8vg1rPMZiBgXoGqwRLEWz9BbB1TvoyKp8KtgbvbhwOgrUIVrbAXATzWWB7EuxAgg0SbEOCgWCGBbaEZuMmnN57BOOUXgF2nWDPCZYk2HOCXY/4UXFlbDB1CdHAMdgTDDkTWHB2gXn77piR4j3sEiwStcF6XgDbQqwwjdV9go2PYIpgawQLrz3H+mUH0gRbE2BPCDYYRJ/nCjYIFn9Qw+HI8MtgT5dhTgXQS7CjA4KtbwPpFAYtMAP7eZ81qWOwAQnW27LBnOOLSC2oeEyw6A0d1e+PoxY9ButgqD6OFYKlCbY68CHBxiCGZmwilJ3E0aeCbcC/2wONYG2CHcY2JZjHAstLsH0JNkywNZ8NphKsGeP/lGUYjRvQvQ8IxqODYAWCBbvApgn2jGB9BHuWu4fpSBAVx7gNpi8h7DsGW8CIsxdPusE8qTfN2OE9bBMsFrbBoi+A7fD/rmPlgGCjI7hGsCzBohsrWJ3UMECwrABbIFh/GP28tdcIlnzQQHFk8GWwxSzMyz4kCFYSYJu7QCqBYQusg1zeY00qLMGGJFisZYO5x5eQeqbiEcF6ruko/2AUtfAxGI/D+iieE2yAYCsDHxFshFwCbBuR7ARKnwq2heBuFArBTIIVY9sSzGWBFSRYToKNEizrs8F0gtUtsCw6jWtQvQ8JZlhgJYL5u8BuEWyBYP0EW+BxdCvCIEcbtcGcSwh6jsGeYcwZw+NuMHfyDbO3fA+btTZ6g9cssPALYDkkClfxvPgRHMNDuEGw5UwHPVurWJlQMESw5XGCPXsKZ18Yg54MsgTre9DCwQgX4yzY0hrMSQ9SBCsIsO09MLLAqAUG7ORd1qSOwUYkWKRlg3nHl5F6rmKWYPEpHYf3hlELHoOpGKsP4xnBhgj2fOBjgg2RS4DtIpYdQ+FTwXYQ3iUWwRSCHcR2JZjDAitJsB0JNk6wZQnmJFjVAlsDGlOcyxzBGMkS7Ihg3i6w1wj2hGCDBHty8ACvCTBl2AZzL8HvOgZ7jglnBI+6wVyJ181E9R7WayYS/ikLLPgC2D5ShSt4dvgxHIMDuLnixdJ4B/GddTwfMzFCsCUB9nwRzmQYw55xLBOs/0Eb+yN9L4Mtb8CccCFNsLwA283zdWIYt8BUbOUd1qSOwcYkWKhlg/nHszyfVNwnWPKyjuKDQdT8x2A6JuqDWCDYCMGeDdwn2ADE0Iw99GZHkP9UsByiu0G0CaYRbD+2J8E0C+xIgm1JsAzBliSYm2BlC2wDauMyOt55gtUtsArB3B/PE8w+n99Y1vDYp3FX0fG4+BBvCDBz0AbzLsHrOAZbwaQzhNluMGf8NTNVv4fVmoKU97IF5n8B7ADpwiUslO/D0Z/G7VUvFkc7SOY28WykjTGCLQqwlSU442GMekaxRLDBByZyI8mXwbJbMMccGCDYngDb42vEIshYYDo28po1qWOwCQkWaCkWWHB8DalVFR8TrG9Sx8HDftS8x2BOTNb78YRgYyvXsDAwC8WRJhegGHkkskPY+wSwNsEUXpw9u360COYgWC6Wl2CKBVaRYBsS7BLBFiWYl2CHFhi3x8Yk5/oUqlG1wGrOOpwfPyWYfT7fyWp45NG4q+h4dDiHOwKs3W+D+Zfg1o7BVnHZGcB9CVacIJij97aZbgowDSn3pAXmfQGsiIFCBgvVWWh9Kby+7sXT4Q769rd5JbcwQbCnBMPqMvSeMMY9w1gk2PADBTsj8ZfB1nZgjmgYItiuAMuXgEgIlywwJ9byCo7UJIISbFKC+Vo2WHh8A6l1FR8RrH9Cx/5cH2ruYzA3Ltf78JhgExbYI04wRS5OwygglR3A7ieAmQQzeXHGd71oEMxlgRXgtcBMC6wmwdYI1pZgTyWYvxbimSfAduBsTKDpXSRY2QJrEEz/eJFgHmtud9Y0zLo07ioEK8/bYK0+GyzIC185BlvHlNOHjyXY4QTB9J5b5oAhwBzMqicsMPcLYIcYKowz7+Hkkwm8uenF/GAH/Qe7eDLQwCTB5gmmrmehRsPIeAhIsNEHGrZGel4G28jBHFIwQrBtAVY4AkIBXLHA3MjmTVTUJPdxG+yyBPO0bLDo+BZSmyo+JNjgmI7cfJJX8DGYF1P1JB4RbNICe0ywBLmAtlFCOpvG9ieAKQRr8+JM7rpRI5jHAivBbYG1LbCGBMsSzJRg8xIsSLADCywHd2MMdW5tqnFogbUIpn7MKsqgy5rbnQ0N9x0adxWCVZ/aYI2kDRbm95nHYJu45vTgIwlWniCYFrtpDnUe2GD6mAXmfAGsjJHCKBaaj2HGe/HWthdz/R3mIHt4nK7h8mOCjRJsk4dtOIxLnn7ME2z8oQMbQ1HUCaarXWBbnOyAiTGCbQqwUgUI+HDVAmNAk2+jpibhlWBTEszVssF6xneQ2lbxAcGGR3TsPGUCrh+D+XGtHsfs0FNcXhFgTwjWSy4BdkSwFDY/AUyzwA4JxjIRwXwW2BGcFhiLCgRrSbBl5z6f9xTMTbAwwfYJZij7BBsh2DJggV1Hm2C4zx2o32HN7c6Who81jbsKweqLNliNpbqSAFuG2p6SYNu44XThQ+cewcZRnSCYGp02R/DQBlN5KBNMfwGsirHCMBaMJ2j3xPAOt4yHfR0MH+bxKFXB1BOCDROMk+4Ew7ji6cMcwTJzLqwNhNEkmNoNtnMAM93GBMHWBdhRDfB5cN0C82Mp30JDTcItwa5JMEfLBouP55DaVfFdgo0O6dha7OEiZCVYEDfqPbhPsCkLbIELGyOXAKsQLIH1TwBzWGBlguk4IljAAqtALwuwhgXWlmBLBNO6wJwEixIsR7COQsDGEMEY3ltgV2ESrDObhd6nWXO7s6PhI0XjrkKw5pINVunh2SnAskDrMmywXdx0OvCBM0+wUdQnCKZEbphj6pwNhiELTH0BrI6JAiOvzgJa0Qhm9rx4kOxgtFzAbOII1xYINkiwXdbT/GFc9SRtsHkPsukgDIKhGyxXhJLi2UewVQFWaQAeF6YtsCAW8w201CScEuyGBNNaNlhynFHrnorvEGx8gEHKUhQNZOEJCbAwbtajXWDPCBaBGG2jRrBeCywgwVoEcxOsfgJWJZiKEsFCFlgNKsGa3Kp8BDMl2CLBHF1gOsF6CLYT45tQigQbINiaBeZWrjCyq8N4tAY9qVhzu5PT8KGpcVchmLFsgx1F0aoIMP4cgxYbbA+3nRrBCvBlh9GcIBjC180Jfd4G6wxYYIxRu8CamCz0YwHP0AqHMJP34l68g/FqCbO9JVx7RrB+gu3toOkN47onboM99WE55UeHYJ0uMHP/kG+kYYGtHFTREtmuyyHBwniar6GtJqFLsJsSTGnZYH3jB0jlJVhax9pyGK1OFq6QAIvidj3cBfacYPwCR9toECxmgfkkWJtgToI1T8DqBAMKBIsEBFgDIJhRqMBDMOUTwFSCxQm2RTBVIVIjTTDuOMYhA4hL0AjWfLwBPW5ac7uzT4S2xl2FYJ2sDVbiPGoCbIOFZAYtFlgerzsVgpXgyQ7CmCCYErpmTjif2mAGyy8E67wAZuByoQ8L6nO0ggHMFLz4QU8HmfoRZhlFXXtOsD6C5XNougVYjw22GMBSgpkwwYwuMOWgDKW3ZoE9J1i70QYcGsFyFth8vgJTTUKVYLclmNmywfrHi8wLJVhKRzYbJEYWwqXR6iFYsAtshWABcgmwFsEiFphHgpkE0wlmnIAxt9vtIE+wWECAtdAhWIeBkYtg2ieAgWBJgm0QTFfKBEuh7mVBmGC6koGDYPUnDPd72tbc7hxo+G5LzJlgWLPBCkG0GgKMrZrKmAQr4E2nSTC+frYfnQmCqcGr5oR70QZrpiww4wWwDqYKSSzoK2j5fZgpefH9aAeXmhXMRvK4tkIw3upqYR9NpwCLEmwOmaUAFnu5vRKs2QWmFXm38GwQYM8IZrZMoip88/sW2Fz+iG8qCbh4pW1m8LoEa7dssMHxQ6RKEiyhY3nNz00gCy3Erb8VJ5ifYIsEmyLYKsF8EKNttJHOhiwwV8MGUwimEqyzfQxmEMzAHsF6AwKsDYNgKJTgIJjjE8A6BOsj2BrBnEqVYAmC7VhgqjIOF8GqCzvQoy1rbneKGr7T0HBLgKkbNljez/cvwHZQPxqRYCW85WwTrAJHtg+YIJgWmDInGIJaYPWEBdZ8AQy4VoizYryKlteDmSMvvhfu4ArPhNkQwVYZFcYJVjpAUxdgXPTgPDLLATyNOaFLMNVYRUeAHfJFI0cW2ALBlLbCfd8kGK9UbxgPmZdpahIdF8/EzXG8KcFaLRtseLyM1JEE69WxtOGFUs9CCRGllSSYl2BLBLtMsHUoDg+5+DXDJFjAAnNIMI1gSPUD28dgLAjsNrFLsERAgJloEkwtFKARzHUCdkCw6AmYQbD+E7A6wXoJlrPAoIzCQ7Dysxz0cMOa251DDd+qaXhNgOlbNhhjg1ZbgOVQLQ1JsCO842wRrAYtm4Q6wbXT/VfMCf+yDVbttcDqL4CpuFHowYJ7HS23CzMVL94PdnC108BsgGDrfZjvIchRkTACLEiwp8hwceYjOpwSDMY6QfqhlZtc3JIF9oRgGg9fmG2CFfn1MB7kC3CoSRiuHYKN4i0J1pBgo+NVpCoSLKZjccsNrZqFGQLv1j6CuQm2TLBJgm0SjHcIR9tQCOazwDQJ5iBYh2AiwrXBQLA6tgmWCggwBXWC6YU8L6w0PCdgRYIxhZFgzcoOBmsBCdYkWIxg+xZYRxmGj2CHz/ehB2vW3O6UNfxFRcMbAsy5Y4Mx/2uZAmwf5cKABKtgxtkgWANKNg59gmAO32VzIpi1wcoxC6z6ApiOm4UoFrybTAAdmKl58Z6/g+toYdZHsM0kG4wEqxyCHSqC+Qm2iMxaAHMhlWUWG6zD9obq7ePi8o0EChbYY4I5FAdXs0UwXo3eMO7l83CpSTRdOYIN4x0JVpNg4+N1pGoSjBfE0x0nHOUs2iFezK1+gjkJliXYBMG2OUE+P0fb0AjmscAUCeYimEEw/QRMJVgVmwRLBwSYhirBnIU9XlNp+PqOwQ7hcARPwOqVHIZrPthgBsEiBONWSTBDGUSAYMUVRpP+ijW3O1UNf36k4Y4A49ltgW070VIE2AEO82kJVsPneHd9wLvMzPbAOUEwp/eSORFes8EOIxZY+QUwJ24Xwljwb6Ola5hpePFttkGm1TZmPQTbjmM+TLBaGc2OAGNizfJKZiOAhzzvvRLMYHtD9yah1TtQCC3AHhHMpfEOaDUIVuY3h/GD/B48ahJ11z7BBjEjwSoSLDPeRKohwUI65nM6XIdZtEJg6D9IMJ1gawQbI9guwXgHc7QN9u6yLgvMJFhTgjUJ5jwB0wlWxjrBBgICzIEywdyFXbQJFjgBKxPMfwJWrexjtOaRYB2CsdXiLVpgTaUfIQG2WuQFe2TN7U5dgjkJxjvRAtvU0dIEGNs6eymulwGzt4G7PL8+4DnWzkbhniCYyzNpTkQ3bLBiyAI7JJhxAuYmWBALwV3mEApmWP75pruDW7rJehjBdntY2SBYo4qmIcDcmAsuI7NFMB8beCdguwSLQ2vyTvDsnYI5PAzvagSrQoB9P78Ln5pE1cUrdLP/BOxIgl0aN5BiG8UCC+iY21fhKWbBNWJyPUwwVvKHNgg2QrA9gnE75mgbLoI5LLA2wQwJVieY+wTMSbBDrBJsKCDAXBKMFyvBQidg3Bkc3i6wA4KxUGCBMStqBAjG7ZBgdaUPEQG2zh3EXbLmdqcpwXSC+Q9sMNZHWw4BdoiD3QQ6HYIxSr3LCPEDRoqtbBjeCYK53RlzomfLBjsIWGBFgnVOwLwE82MhvMdN0MRM24tvODt4je9s1kGwvSjm/QRr1XkbCzAn5oJZZHYI5jG6wPYIxm6uofIq2T0FczGKq1UIVocA+x7BAmqc6Q/PtM2+E7CSBLvC1k6qLcF8BGOs4jlYEx16lq8EGKtAQ1sEGyIYzx4HDyaOtuEhmGaBtQjWscAWUU31wbu9RbAIwdwEK2KFYCMBAeY5AWu0U4icgNUJ5u4CKxLMIcF4DDR8BCtbYFUliZiTYJt8zHxKzO0Ot2cLTCVYsGiDrQItlwArY3+7l++YYJE2wUoEU9DIBuEXYB7XhDkR37HB9n0W2AHBkCUY8yyYfoJ5sRDNo9VpY4aAf6Z3eGCy4qwRLM/cyUuwdhNNJq3XPVzE4BoyOYK5ml1geYJFoXV0LuL2KZiH+2bliGBNCLD3CRZSe3DoYki8ncQMtyUBVpBgV8cZ2PGsscDEaxU78OxvoBIiXEuAdQi2Q7ABghX4Wm1OXoD5CKZYYI1GH/9CgC0RLEmwHYKFCOYl2AGeV8IY8wkw3wlYzUwgljwGaxLM2QV2SDBNgrEr3vAQrCrB4jbYdhWGXrLmdqcjwUAw3lEW2EoHLY8AqyK3GWM5j2AhE3edBYJpqDF9CY4RzOscNyeSOQnmQT7hxj7B1DWdbY8CwYIEc2Ohp4AWk88ZxYs/VTu443VgViEYt8t5N8GYwzSrAkzFXHADmX2COepdYAWCsTEIJ3OJzVMwUQQ8KhHMwClYFEVXGfpuHDMRGywvwa6Pq0jxKrbAXAQ7NODJbXWBGQTLESxNsBLBWuSijxFAes3E+vAWagRTLbBlgsUJliNYgGB+gu3jWS2MCY8AC5yAVZRe9MaPwQyCcXs+ASsTTJFgToKx/uitS7AeG4zRp6EKsCjucBUsMF68j6JlG+y5gZZPgNWxsx6BrhMsoBAsTzAHKkxfwiME8znGzIm+fQnmQp7Jbo5g+gbBQgWChQnmxEK8hFazgRnNiz9BB2/7XZg1CVbyY563rMrDtlkWYMBccAsZbq8PtWoXWIlgQeYhbpZX1k/BAhGgVCBYB6dgYYJVoe/1YCZkg+1JsOlxHSlNgnHR5pgmeHZ2usCaBNsnWIpgTMIdDXIJsBDSG22sDxKXYLoFliVYD8GYCI/6CBYkWA4LjTAmuSWLYu4x2JEWQ6LnGKxDMLULrEowU4KxPdVwEKwpwaI22F6Tm5wAC3MrlGAGwXqqNtizJloBAdbE1iqfhxeFya70XVbqP2DF/mjLjegQwfz6qDnRfyDBHMjH3NghmHOLYAy/YbI+V9IJdoRWvYYZHrZf73TwbtCD2TbBmEjP6wRjMNY8FGAdzAV3kCkSjGWaU7Ajgvmh6Ux0zdVTsFAMKOQJxlU9AQsSrA49H8VMwAbbJZiyTrBRJ1JcLAtMI1i1TrAcKoFjsDrBDjC1msBCukIwbu8cbSOC9FYL6/1bOCKYU4Dtr6GaiMLLbdAx7CFYmGA7WGARedIhwCI2WGmbYBEkosdgDHCYLZyC1QnWlmBegmkEMyQYy1wCLG/A6AiwIIMNCdYkWLxugy3UGekKMINgARvM4yDYLsHY+GT6Eh0gWEAbMScGixJMQz4iwXYI5hNgrM+xsrAQr6BVZSLnIphBsLAPsy2CVdyYJ4jKRWwWBZiBuWAOmUOCmYddYGxVeL0shPqZhAqwHlhgER6wDOWn3WoXmJ9gTSasYcz4usA2CTbsRsolwRSC1dnc3NlHhbGLDVYlWBFT671YSNUIViGXAIshvdPAet8OSo0k3ALsYAPVXkZfe0yEB10EixJsCwttgmkCLGaDHQmwEBLhYzCVYCzPnYCx1cMitg3mJxgTbqY+NljQBit0uAYCzM9wXoLVCZZs2mBPWM2JCLAOwXw2mMtFsG2C+XDE9CWaJlhQHTYnhhnKWmAK8iEJxm+AR4CxPsc5L8RraJWPMOMhWJNg0QBmGwSrOTEPgvE8aR4IsCbmgvvIlAnWLnaBcZtlR1lzB5ngCrAoLLBYAtjbJRhf7wTMSzADeinI1+sC2ybYoBcpnpMWGPOmuWaZYAeocCv2CDA+vj9wiCke3AsJVggcR+QSYL1I52pY53ldasRtsOIWqrEgvHkmwv0OgvUQbAMLvNMmFQHWa4NVBFgAieAxmE4wowvMIFhDggUJZhIMEsxvg5UY9zUFmJcJczeYIcHKEgwEY5degDHluevcJFgAR0xfoimChZQhc2K0LMFM5AMSbJ8L6BJgrM/VOgRjn+qwxCueYIzE3u0JYbZGsAaT1w7BPE409wVYHXPBA2SqBGsddIE1COaERhSlsUqwMCyw3hSwu00w7jP8mg3G1MLVgX7k5x3dBbZLsH4/Uj4J1iYYF8WzU0SF+bcFxsf302VMbUew0Mtiq4MrxdE2EkjvV7Ae3ydYjw12uINqxA9vgYlwn0awOMHWsKAQzBRgCRusJsB8SPiPwZwEa3aBsT9Yq0mwMMHaBFMlmNcG4y5l1AWYmwFbNxgDOAvsUIKpBGN1RYDxNe861wkWwhHTl2iCYGEMmhOsz9lgbeR9EuyAYA4BxvpcwyBYC61igWcKwaoEi0cwWyEYk9h5g2D8uWZOgFUxx9wiUydYY78LrEUwHZo/CqUmwIKwwBJpYHuTYDzEvMdgToKBjUEvz8wusD2C9QVZ55NgLYJ1igQ7RIVnigXGx/dTVUzthrDAarsi5sDRNlJIHxxhveeAYKwaCLByDtWQF94SE+GkQrAkwbJY0AjG3SEXS9lgDQHmQYJnsA3mJli9CwwEq0gwPnejRTBdgrltsIoOoyrAnLjj7waDBCtKMHbRVx02mBIg2CrBIjgqdhDtJVhEGSBYXYK1kPdIsCLBNE7WZH2u1SRYG62DPKM2gpUJloxh9ohgTHPmmwQjZHNHgJUxFzxEpkmwWq4LrE0wFVqwBwqzxAndDwssNcAEeZ1g3ENOwHSCqdBrbkalXWB5giVZmGWN0gLj3T2HA4KVwVqqDcbH93klTu0FsBAxCZYnlwBLI819aT1aJBjvAgFW3UeVO4r3iIlwnM1Fs49gy1hgYXeyJcDSNlhLgLmQcB+DeQlW7QJTCXYkwXj3NhoEc0owpw1Wc7JVI8B03Al2g6kS7ECCOQnG5EeAsaJ917mCD5wxHDF9icYIFlX7CdaUYA3kXRLskGCKAGN9rl0nGMsj+3vMiwh2SLC+XsyWCGZ2MF8nWMiP5pYAO8RcsIyMQTBWsU/BTILxGgjHoRwJMC8ssPQQsL5KMH8XmEowHXrDybyvC6xAsHgUqYgEqxFM3ScYa42KBOPj+71NTOV9WAjxZxx75BJgA0gfFrAePiQYzxkBVudi+5zwVpgIs7nY5sWZ3F3CgotgDQE2YIO1BZgDCQZMNpifYOUuMHYRaiUJxvOxUSOYW4LpNljDDYNHipjbnXA3GAEtsH0J5iaYYoO1IwR7TrBeHDF9iUYIFtPSBDMkWA15llwssDLBTAE2jNtmlWAKWrldVh4Ixtvz3f4EZhmOX2N1er5KsEgQzQ0BVsRcsIpMh2BHW11gCsE60KJJKCUB5sajA4INjACrK5hmMHIKBoKxl8Ytb8bsAmN6Md3Tg1RMgnGh5vQcweqomBKMj+/HDDZdPRC/v644dskFgg0hXc5jnRdTqcFIToA1i6h6dHhrTITZXGzz4rTAPEFM1g5PwUwBpiHBXcEGCxLssAvMKcEYjiuMQBsVgnklmGqDsQZr8A4Xc7sT7QZzSrCcBPMSzLTBWjGCPSNYAkfVOqKhOpQevY9gHdhgFeQ1CVYlWFuAjeK2UiaYhtbONmt7BDsg2GAKs3mCsZo7XyZYLIzmmgA7wFywjgwIVtroAtMIZkDr6eOdIsCcsMCGxoCV5wRjeHQC1iGYm8mtytplF9gRwaJxpHolGB/POXcI1kSlLcH4+H6kg6mSCwtMCUQZTIy2MYJ0dQ/rftYl2XGwwMSCcuv1NpgIs7nY5sVpgfn8mGT14gRMEWAKEgzJbbAwwYpdYG4JxnBc6SPYEcH8EowVPgHW9sM4EGAMMnq6wRiEWGA7EsxPsLYNxkbo3WOwehXRAMF6HSmC8SK3wI6QVyQYnwwtATaO29ohwRxobW1iJkGwfYINpzG7RzAevvOHBOuNopkVYPuYCzaRUQlWWOsCcxCsCS3ez7NIgPHNCrCRCeD5M4JFu8AMgnnBhgC7A11gvKOmw0mkEhKMd9yce4tgBtsvEoyP74eAqSMH7xQXwTbJJcDGkK7vYp0loxK3JwusU0bVwZflzzqCNYKN2mA8jyePWNM7BtMEmIkEjsGiBDvoAvNKMIbjCqv/jRLBghKsY4OZQRj7AoxhPC/+UzCG+RYYi9IWWJBgLRusljgFY7oS9REs7kwSTIUNVkKeFWsLjFk4GgWCZXCbV9NC3IXWxjpmUgTLEWx0ALO7BGMJab5IsESPDebKYS5oIKMTLJ/tAnMRrA4tOchoT4CpsMDGJoFnCwTrwSlYk2B+6JzsDMs8J2A8s6aDfUilJBjPtDnvBsE6LDcRrC3ANnCfUeQUo5AFl4dg6+QSYBNIN7ex7m4SjPU+AYYqqhpbQLyTHf4KwcaR3BNgboLVkYtIMIcAayPROQbrIdh+F5gfo0RaizAcVwYJViBYWIIZNhjjcSMnwJgoJ7vBmEhbYBsSLEywhg1WSZ2C8bmiTJmUhCtBMB02WAH5tgRjnQs1AXYJt10HBPOgtbaKmTTBuEDvjg9hdptgnjLmDwiWittgjh3MBTvIMNl7uLfcBeYhWBVa3zDzKQEGWGATl4GFJwSLd4Fxgq4gdM1gh7sLjFHhtL8fqbQEyxPMv0YwoFIjGLe02+413PfpmGIxdsHhI9gquQTYJNLGJta5ECU+jwXG1xEL6mUC7mBzsc2LM5kXYE6CNZELSTCXAGshYQiwXBdYQoIFMdoiGOueTmWYYHmCRSVY0wbTojB2BBhLUcz5TsFYqrLA1iRYlGBcf75P8yh9CtYpIspynZJ0xwnmhA2WZ1VcgnW4ohUBdgW3uc0txH1oZVcwM0CwLYJlRjC7STDfIeb3CZZO2mDaFuYYP2TcBOP2cgrmI1gZWv8oKxYCrAMLbHIKePKYYExITsCqBAtDZ3I6wwTrBIxByLR3EKkBCbZHsGCWYCoqzIU8XKzb7izuM4mfYrVhgdUJxbECMdrGZaQ761jn65Ya7Fm1CaY3UTXZAlKccLhLBLuEZEGA6QQzkAtIMI8AayDRFGD7XWC9EiyM0TbBGOQ4lVGC7RGsR4LVbTBHD4wtAcZib383GIvBFlhWgvUQrGKDlQZOwXCAKI8fJeXpJZgbNtge8tzfLTAQjG0PC8zHCnY8gNYywYbc+PoGwS6NYXadYIEi2/QEG+izwZQNLqKKjJdg2wLMJcECBDuENjgOZVOAGXh0UIfrMsEenwUrEywKnVfUDM+iEzBWNqbdw0gNSbBdHXPhZYLpoqUGjybAlnGfActUq82KRYhgz8klwKaQxirWVRAMNpjTQJUpi5eBlsNZINgVJEsCTCVYBzmfBPMJsBoSdQF20AUWk2BRjJoE8zEcV8YJtkuwuASr2mCuOIwNAcZ2ymA3GNstFtiyBIsT7MgGKwzhBIzpSlQjWJ+3h2Be2GC7yDNZtcCY4KHEVeAkbgdYwY6H0Foi2IgTX18j2JUJzK4SLHSA+R2CDfXbYOYa5oI6Mn6CbQowhwQLEawIbTgDhXnXBK/uRwdNuKYI9ugs2CEn2AOdVZOZUheYSTDnKFIjEmybYNElgjlR4bX1W18J4u5ECXN5Df/zP+UdZkZwqZ+J8C5zLOMa0uoK1qESrGODuTuottgCYgfCoecJdhXJIwEGggE5jwQLCLAKElUBVuwCi0iwHowqBPMwHFcyBNsmWFKClW0wTxLGmgA7xJ3hbrAeCbYkwZIEK9lg+RGcgDFdifJuVdK+GMH8sMG2kWeIaoHpBCtwnxFgIVawWYpqLRJsTMfXswS7OonZFYJF9jG/RbCRQRusncVc0IlMkGDrAkyTYBGCHUAbvQRlVYDV8ejAgOsawWa7wAoEQ5Fgcei+MmYKXWBMQqf1caTGJNgmwXoWCea2wH7/32ZlZLKG+W0F/9k/NNFhvfOf/g1+geM/+OMIHi8y6OBWX2KpzQLzAtUGW0AMcByqBKsIsA7BVORcEiwkwI6QKAuwwy6wkASLY1QjmIvhuHKJYJsE65NghzaYr88G49zujHaDxSXYogTrI1jBBtsbOwVz7iDK7Vvp90cJFoQNton8kQRzEizPk1yARVjBjsckmIqvLxPs+mXMPidYLIf5DYKNDcMCay1jLuhGJkywVQGmSLAYwfahjV+BsiLAqnh00IHrhgRzSrASwToHBEtCDxxiJt8FphFMzSA1IcHWCRZ/SjCvBfbef6IiHTHwfz9Szd//v9rKUCaI3/6rvFU4fvl/jKOU55ZoOAnWtMH8Kqo1toCYKDsgwWoCzCCYjpxDgkUEWAmJQwFW7gILSLAkRh0EY3fbqVwh2DrB+iVY0QYL9MMC49zujHeDJSXYUwnWT7A8159guxOnYExXom0DykAgQrAwbLB15EsSzE2wPW72fAO3I6xgx3slGPD1JYJNT2H2GcF6dzC/RrCJURussYS5oBeZKMFWBJgpwXoJloOWuQrluQAr49EBGwI3JZguwY4E2D4P6T7ooSJm9rrAHATDJdy4beA3725iO+dgAbpiLmUdynxWwR98rUMa4O9/Q8d332/ic2/58bUvVvk3wO3/vB8JxyLWm26C1W0wbt3iF2i8PibCHQnG978QaBLMiZxGsOoxWAGJogCrdoH5JFgfRl0EY2DiVK4SbJVggxLsABZYaFCC7Z8B65Ng8xJskGB7EmwSd3UJ5t1AtNWBMhgMEywKG2wV+YIEY7WZfXkJliVYQoJ18PVFgt26htkFgiW2IBZLnRy3wWqLmAv6kemRYEpbgiUItgPt0nUozwTYIR4VVZyAqRKsIsByDIP7oUcObLCwBHMRrHMFP/WFKr72DmP5TxhbRcXM5TtKMMAaX7yNDTYPf/7vDiPtfYL1updgVRss7ET16ADeABNhQ4K1BFidYG7kFILVj8HySBwIsHoXmIdgRRvMQzCF66ZcJ9gKwYYl2L4NFhmWYLkzYP0SbE6CDRNsV4Jdxl1VgjF9iYr7ZygUIlgPbLAV5PMSzC/A2vwOAcaDO56SYIYEu4HZJwRLbWB+mWCXMxLsKXOjIDKMKi0ws2WDdVLQeVtrV6ahLAiwIh6xwHwCBglWE2A7TDQHocf2bbCgBPMQzLiKmc818KWpHMaDLXicJol++NguavjOkxr+wXtOLO2XbbCoG9USW0AhAjQlWFuAVQnmRc4kWPMYbA+JfQHWPAU7dGGexe5qTYKZDMeVaYI9J9ioBMvZYLFRCbZzBmxQgj2UYKME25ZgU7gLCRbMIlpToQyHgwSLwwZ7jvyeBON2gT0uhgXGScTTEqwpwW5ynQmWXsP8EsGmLkmweVYfwsgkJVi7YYMhDd25Ae3qLShPBNgBHpWdOAHrSDCGxGJSRRZi9d6cDcb6nwXmI1jzOlLX7DPs8rKKv/5vLJqfH9AVfMZRawJ/90/r+Cd/wYulx4tqgS2gSByOugQzOddAmWB+5NoEY4XGBttFIifAjFOwsgPzLpa3GhKszXBcuUWwZwQbl2A7NljveBeYglOwYQn2QIKNE2xTgl3D3Y4EY/oSZWlOGYkECJaEDfYM+V0JFhZgXAcLbJFgAxKsTjDYYI8INiDATIJdkWBzrO9Fkek7BquBsQ0MdQC6vgbt+mtQHguwfTyqunECZkiwlgRrEyyxY4OxQmKBcYubrk8jdcMGG1/Q8YVfnMPfmHaRwh4HbP38yu8FWJEpIhKL4vNXi7iS1viVF8d/+scG/vmyD9U8W0CxJBxVCaYIsEOCBZFrEaxzDLaNxI4A65yCVTXMsy9WbUmwFsNx5TWCLRAsI8G2bLBEpgvM7AIblWD3CNaQYOsS7AbuGhIsuoQoz1VlNOonWB9ssAXktyVYVIBxohbYU4INSbAqwVQbbJZgQ2fBHrKC3oNMfxdYx4ShD0FXCTZNsEcCLIdHPE9OwJoSrC3BWgRLbdlgbgkWIlj1FlI3JdgTHb/x64/xM8Os4Moxv2LgV/9xFFOBPSwURhioPMNXXtPxH/3CKaoYB2UTX/rvPajubcHb2wdHWYJpAqxIsDByDYLhGGwTiS0BhlOwuoJ5jeWttgRjPdEGe0KwSxJswwZLXeoCa3eBjUuwHxCsJcFWJdhN3G1KMKYvUQaEyljMR7B+2GBPbDAnwXoEGBfCApsn2MjLYPcJNnIW7AHm9Dgyg68AA8FuEWxWgjW7wOoSzJRgDYKlN2wwpwSLEKz8GlK3JdhjHf/l35xHJsILS44//7iJv/3PejEV2sVCfswCE+O/+dUevHOpyj+djs//rhu7a5vwJvrhOJRgji6wGsHUbjAv0qZ6CtY0Mc8zrWpKsJoEaz1G3X1Fgq3ZYOkrZ8AUCZaRYN8nWFuCrUiw27DAdILFCcaAUBnv8RJsUII9tsF0gsUFGK9KC2yOYGMvg31MsLGzYPfYBU4iM/wKsA7BXiPYfQlmnAFzE0zpBluzwZizWWAxgh2+gdTrEuyRjj/6L55y6U/H7/9JDb/77RSmuMgLexMnYL/91RhmrrwI9sbveFBmy0h8koCjKMFcXWAVgundYG6C6VjUJZjRxrzJ8pYiwZgMWmDtR6g7r0qwrA02cPUMmCnBLkmw7xHMlGDPJdjrNphKsCTBGBAqE70egg1LsEc2mEqwpADzSLCHBJt4Gewjgk2cBfsB5tCHzOgrwAyCvUGwjyVY5wyYk2A83MWkbLCsDabWCWayeUew4h2k3rTB7u4Y+K//vRUu/en4r36/jH82P4Cp2CZLUpMW2K1RFf/drztZ/ec3yPFks4Nf+4OA9ZEPFtiBBPN0gR0RzNkN5iSYE4uqBOu0MN9meUuTYKy9WmDmLOr6dQm2TDC2i4bOgrUl2BUJ9j7BFAn2TIK9aYOBYH0E2yNYJu4m2KgEm7XBwASwTyWYT4I9INjky2AfEmzyLNj3MdfpR2b8FWBNgt0h2EcSDGfA9DDed3SDLdtgDFsssISK6YO3kXrLBvtqq4Lf/GubXP4Xx8KmhlargY0804v+sjmaYJh6Zvz7/2sDH+zHTsHYqbDAfF1gJYK5u8F0grmxCAnGdZpvsbzFJN8CK0kw5T7q6rQEWyIYG7IjrwJjTzB5VYK9RzBNgi0QrHMK1iFYP8EYECqTCRfBxrvAnNjhlePs56z2WAVVBNg9gl1+GewDgl0+C/Y9zBmDyGReAVYn2NsE+1CCqWfAVIK5usGWbLCOYYOlgOn9d5F6xwb72yw8/9KX9vhGP/tY2VXw2//PIT7gHFzx5ClYToIFusAKBPN2g7H0ZXqx2JFg3InmGyxvuSRYQYJpH6OOWxJskWAdFMdeBca6d/K6BPs2wRwS7AnBcArGosPRIMG2CXYp6SRYpgtMxw73Zucgyzx7IQn2A4JNvQz2XYJNnQV7H3PNYWQuvQKsSrB3CfaBBNPPgIFgnm6wRRvM6Nhg6Q6mcz+B1IwN9t+ObuPt6RJ/zh7Pcy6srBXh9ITMq4N1JRrgLnFm8MfwP3yjhv/tW22C9Z2C7UiwUBdYnmD+bjAQzI9FQ4LpFczXWN7ySLC8BHN8hHrnNQn2lGBAceJVYOwJJqcl2DcJ5pJgjwmmnoI1CTZMsE2CXU45CHapC0zFDqMf57BBsIgE+z7Brr0M9h2CXTsL9h7m6qPIXPkUsO9KMOcZsA7BfN1gT20wLrIFNmBgeufzSH3OBvvDN1YwnK6TwR7/58cx/Pm3FnF/+xKm0s9RrV/GL76zjq+8pbB98uKu+I/+omH+49m4cgK2JcEinwbWIVgQi00J5jzCfIXlLVbiT8BMgrk+RN14A50Owcx5gqkoTr4KjD3B5C0J9g2CeSTYI4LpL4OtE+xKn06wK11gwA7zC+doN9j3CHbjM4J9G3PVcWSufgrYdySY+wyYQbDAWTANc9zrLbAhgm0R7K4N9v4vzLPlwtWX4+/8v/1Yf/oQ9zcJNvAMC+s3+fVnmB4FfvdrXREHR4PP95f+UQi5VeZhKYJtSLDYp4GxXmOGbTCNYOxSz7Md9TLYB7yT76ADATaHGFtVxcs/ArBVgk2lNYJd7QLrEEw7A/Y+wW5+RrBvYq6cQeb6p4B9S4J5z4A1Ccb8SUzqFEzhBaBjty3BNgj2RRVr+hH+4ItLXPrT8e/8kwkGD9/H/XWCDS0Q7DYU8xm/AvzDv+nFRNfdKMa/+YcB3J9lpSNFsDUJ1vtpYE2CRW0whWDeAsFY3noJ7Luo199Gh83KqvkQMdWJ4tSPAGyFYFf7VYJd7wIzCOY4A/YewW5/RrBvYO7wEjLTPwRMJZj/DFidYJGzYCYvACd2TRPhyTa+UH0dxpQX4fgu/sPr3A66xs/+vesYN7+N+6sEG3lCsNehtG2w//0/dqIv1uKfTsdv/GEQ9x9KsKwES3waWJ1gPTaYSTB/nmD+V4B9B/Xqu+iwI1E1HyAGN4rXfgRgzwl2bUAh2HQXWJNgrjNg3ybY658R7M8IduWHg4Fg7Ju9AFYlWOwsWJvPx4qEYuInvwD8zs/6uNQvjxotfu7v38Q1/ZsS7DHB3oTSeoZf+SLwb32Z39Q1DN6xP/l7MRxuysR5mWDKDwOrEixug7UJFtwjWPAVYN8i2E+cgnXcKN74EYA9I9j1QRDsVhdYnWCeM2DfJNibnxHsT7nAV384WIdg4c8C1sJc0YtdzcTf+qqGr95wcbk/eeRLHeyx7VFnjqarQTMdqysRZidnx5/NNfBb30rjJOj4TGBlgiVtsBbBwrsEC78C7C8I9vlTMMON4s0fEdiNIXGH/SjB/oRg1384mEGw6GcEOyCYw8TXfknFTyUj5kC6qmgqV/1fcNQYBf/6PyhhW534lwTbJlj0h4Oxy128/SMCmx4WZ9j5ALOCjiUGHV9Wsawe4ecLZfzKV9ZJAOyXVfT4O/zTp488i65/64/reLJRgSt+6fyB3RwRUeL5AxNh/S/vH+Kv/+vPyQD82v8SxUZxDH/wax8iEVVxcNQxP3gSVdAp8atApdWL7doGvv7tBLtG26xG1HAuwW6NijzsfIL9Jiv7v/DTdi3xnd9JMDcawf/3735guhyK8t58w/yt/+OmIoIOMdr6NaSHP8D6ch9Krq3zC3Z7TFQ6zifY34ln8cbNPLYONfzl34tjKJrCH/3GPfIAf/SdGv6nP+Vr/biBvTYuaonnE+yv7HIB3n5qNgph5fe+GeB7C+O3vzJPHuDv/XO+z4/e+PEDe31CVOvPJ9j4n+uYe/fb8OwMW79I6ncN4st930KjQ5RaFveW3vrxA3sjI/phPx5gHscwbru/IRPnR0ycfwzB3pwUHecLsHMDdueS+J2OC7BzA/bWZfFbUxdg5wbs7Svi9xIvwM4N2DtT4jd/L8DODdi7V8Xv1l+AnRuwmWviX69cgJ0bsJ+4Lv592AXYuQH73A3xLzAvwM4N2Oenxb9xvgA7N2B3b4pPEbgAOzdgX7glPqfjAuzcgH3xdu8FWOocgX3pNfFZUxdg5wbsJ18Xn+Z2AXZuwH7qDfF5iRdg5wbsp98Un0h6AXZuwH7mjvjM3wuwcwP2s2+JT9W+ADs3YD/3tvjc+guwcwP25XfEfxniAuzcgP38u+K/vXIBdm7A/tLM0AVY6hyB/Ss/MXwBljpHYL/wuZELsNQ5AvvFz49egKXOEdhX7o5dgKXOEdhf/sL4BVjqHIH9q1+cuABLnSOwX/pS5gIsdY7A/spPTl6Apc4R2L/2U5cuwFLnCOyv/vTlC7DUOQL75Z+5cgGWOkdgf+1npy7AUucI7Fd+7uoFWOocgX31y9cuwFLnCOxXf/76BVjqHIF969GfXIClzg/Y/w+AfLO19xY+NAAAAABJRU5ErkJggg==";
        
-    if let Some(extracted_text) = decode_image_and_extract_text(encoded_image) {
-      println!("Extracted text: {}", extracted_text);
-    } else {
-      println!("Image decoding or text extraction failed.");
+    match decode_image_and_extract_text(encoded_image) {
+      Ok(extracted_text) => println!("Extracted text: {}", extracted_text),
+      Err(error) => println!("Image decoding or text extraction failed: {}", error),
     }
   }
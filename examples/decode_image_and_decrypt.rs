@@ -4,14 +4,14 @@
     let encoded_image = "iVBORw0KGgoAAAANSUhEUgAAAGwAAABsCAYAAACPZlfNAAA4QElEQVR4nO3cCWxkW3of9v+tfV+4FZcii1uxuDR7ff36db/uWTySxlomkjyyLNtyFFtWAjhQHMAxbMCOk0hAEiNRAmQBLBmCEjuIbdiwDFluy/LzeGY4zfdev252s5trcSkuRbKqyGIVi7Uv9/p/7r1FFl9TM2PLtkCBBxhMo8l363znd+453/kOm9K33v6OEh69i+2SGT1H80gflJEo22EZrgOHfkCaxD3/N/Gi24qRzU4M92fxNGrA47t3MD+ThtO6gLByE8oDP14v23Cz9DtYyN3EQ8cxZt8fwyhOsA4v3n/1r7FYA1LKNEzWdgxb12Hfn8C65UB9RnDsEUqnc7Dt+xC7GULtRQGVh05Mf2sR0a/cxy3I8ORfYmbDgScP7uCj2Tge+qN4Fm9g+sltxL7pQdFzCFt7HfePV/GduoJ7RuAFbqDWtY3e4BhES36yCrvdCWNmEnnjt2E03QAG1yC3yah9asDjL7yP56+WMXUnCMO/iqLQ60P2NI6MpOBul4TF3bu4VXPh+fhnKG8r6DGU1ZgePWiD9GkGL2sxRB6H4X4xhznnVyGfPoOpOgZFqaIytArb1jRQr6njU2zksWgH8jfG4fg4ipFqDW/bHHBJFeDYjmrPLVgGsyjbn6FueAzDTBHSz/7Ye9dgVwnsT//ovWuwqwT2p37k7jXYVQL7kz985xrsKoH9zB+9fQ12lcD+xFdvXYNdJbCf/qGb12BXCeyP/+D0NdhVAvupH7hxDXaVwL7+lalrsKsE9sf+yOQ12FUC+8kvT1yDXSWwn/jS+DXYVQL78S9GrsGuEth/8oWxa7CrBPa1J+FrsKsE9mOPR6/BrhLYj344cg12lcB+5NHwNdhVAvvhh0PXYFcJ7I9+MHgNdpXAvvogdA12lcB+6P2Ba7CrBPaD9/uvwa4S2A+8F7wGu0pgX7nXdw12lcD+yN3ea7CrBPblOz3XYFcJ7Eu3u6/BrhLYF28FrsGuEtgXbnZdg10lsCfTnddgVwns8Y2Oa7CrBPbhVPs12FUCezTZdg12lcAeTvivwa4S2AfjvmuwqwT2IOK9BrtKYO+Pea7BrhLY/bD7Dw3Yz//EEt4bLuA0VcYv/mZdBct2hbGMxB8esPdGXX9owH7pz8/jg+Ei9hIyfubXq/ixbgl/5eetZAL+l/kgZpPeqw92b8T5hwbs7/yVF+jx1fFqsaH84m9WpZ+fMuLP/qSFXMBf+ngIGzn71Qe7O+y4cmA/8aUIfm56DscnDczvykrB2Se9eubHr/zny6QB/sW3avilb9fw175owo98QQP7+r8YR12Rrj7YnSH7lQP7hR8ZwJ++uUSGy1vmRFZWD2Vp0GdAd4cB8RzwF56N8yu4+mC3B21XDuzPfHUYDwOrGO2swGqRyPC9W6pkwrf2PPiN3zxGoX6FwW6FrFcOrLmHPemI4ie+bFIejxu/PzW2clXBr89Y8I9+59bVBLs5YPmDA0sQzPQ9wGYI9uXLwUTSMT1hwJ//AW2fEi2VN+BXfz2HA4+Er4aMCI97MNlb5Vcutv/1Hw7g779Y/u5g/5pg3f8Rwfj3b33fA2y63/wHB5YimOF7gD0j2Bd/b7CvfcmMH7xpIoHWXuxa8P//xtGFg/OPf2kbf/2LBn71vGXzJnzll7LfHexbBOv6jwgmE8yjg2UJFrgE7EbQ9AcHdkQwfA+wjwn25PcG+69+2opwD2X09o/mHXj2Tw4ugIlKx9/+uhvvB4r8jvP2xb9ximLtu4DNEKzjErAGwcb+A4CBYC4djEeQauclYFN9xn//YHmC2b4PsGOCyd8D7FOCffh7g/1f/7WTQ3/e/uY33Dj49u47YL/2dQ8+6L4Idu8v5yAZvwvYM4K1XQKmEGz09wHGN0mMzztgBoI5dLA8wdqbYB8T7JEGNtlr+PcPViSY5fsAyxKs/j3APiPYw8vBvj66jr/2nzo49OftF/6BH46lzQtgt+/s4Nd+3ARTy6oY3bPjp/+3xHdfEjmIBd8lYBLBhn8fYHyTxPi8A2YimE0HKxLM3wT7lGAfaGATPdK7YFWCDf4+wMoEM30fYDmCVb8H2EuCPbgc7C882MQv/Bgj/lzbPagjnpchFRR09jiVkUD9nSzyr/4/IfzzN/MXwT4z4PHjFrBPCea5BMxIsMHfBxjfJDE+74BZCGbRwfjSVL1NsM8I9r4GNt6Nd8HqBBv4PsCeEcz8ebDfxUL1Bh4avg+wvA7GZ7wDNkewBwR7RbD7l4P98g9v4Wsf2jj833/bTMr4vz8y4eP5KeQbTOsNLWAvDXj8qAXsM4K5mmASwRQNzEywgX9LMIlgIR2Mb5IYnwtgnBwqmEkH40tTdTfBXhLsPQ0sEhBv2O0WsCoSshWWYCvYtwlmfhfsY4IZlwh2owXsIyzUJ/EQnwf7lg42dQ5W1MH4jODYBxfBXhPsPsHmCXbvcrC//bO7eH/iPKVfSxrxWdyMG46cEug1SQFvyxqot1JVwW9824C/+y91MLSAvTLg8QctYC8J5hBg++dge7c1sGATrKrGdAbWINgjAfaaYD+ogdV1sH6C7ehgHJ8LYJwcKphBB+NLU3U2wV4R7K4GNtYl9rCbLWB1XkaYYeltBfsOwYzvgn1KMGmFYJMtYN/AgjyOh/LnwWZ0sIlzsLIOxmcEx96/CPaGYPcI9pZgdy4H++2/uI+BwHlK/w8+seNXntl5H7bBPUzCjwYMmHgQwJ/4oAjL587Wv/GNDuVX/vmqZJRbwOYNePx+C9grgtkEWOIc7OCmBtb7GTSwuhrTGZhCsA8E2BuCfUUDk3WwPoLFdTCOzwUwTg4VDDqYTDB7E2yeYLeZBBEs3CmyxBstYDISBiMs3a1gswST3gX7jGBKlGDjLWDfxALG8LD+ebBnOljkHKyqg/EZwbH3LoItEOwOwRYJdutysDf/YwoSB7LZfuk3XfinG2aCxVQwLekYxZ27u/g7f/zi0lmpSfjiL3PQ8hPnYG8NePxeC9g8wSwCLHUOlryhgXU3wWQ1pjMwiWDvC7AFgn1ZA+N4qGA9BNvXwTg+F8A4OVQwWQcDwaxNsLcEuwnDxwQb7RDnsMkWMCBhkmDpagX7hGDKu2AvCdZYJxg7dAb2bSwYRvGw+nmwj3WwMM7A6joYnxEcu3sRbIlgtwi2TLDpd8E217bwW38py8DO25/5W14sn0oE224BG1bT+n/2Z30Y9bMDLe2n/o8cVrfDwIAOtmhgXC1gbwlmEmBH52CHkxpYVxNMi+kMzEiw9wTYEsG+qIEZOD4CjPVPW0IH4/gUFYJZdTBODhWsroMZCGZugi0S7AaTIIKNtItKx3gLmAEJiwJLR70F7DnBGu+CvSJYbZNgoy1g38GCicXZ8ufBPtXBRs7BZB2MzwiO3b4ItkKwaYKtEmxKA+uuvcTuiQOBgVvw1Tfx3/5kgcN+3j78H/wMTiaYOIc1wQZVsG/+F170uhr8rvP2U//nKVa2R4CgDrZswOPbLWCLBDMIsONzsPS4BtZBsF2CwaDGdAZmJthdAbZCsCcamEkH6yRYSgfj+BQlgpl1ME4OFayqg5kIZmyCLRNskkkQwYbbRC1xrAXMhIStAQsDwKFXB3tBsNq7YPMEq2whLA1Dud8Em8WCZRAPi58H+0wDk4ZgMutg0MH4jODYTQ0sQbAbBIsSbIpgawSb0MC+0DGPP/cDXEMuaeWqhIf/vQ+St0GwvRawAfy5rx3gv3li5Xedtzrt7vx3J5Aro0CvDrZqwOObLWDLBIMAy56DZcY0sDaC7RFMNqkxPbpPsM90sNsCLEqwDzUwiw7Wvgbb0RQxCMbxKRoJxj6qYJwcKlhZB7MQTGqCrRJsnEkQwYb8olo/eg6WtBCsBouPUR26oYHNEazyLthbAbZDsEEo95pgn2DBNqB2aPa9VrCXWOQgpaQQTEYdzNAE20Fw9IYGliLYJMHWCTZBsA2CRTSw/+zGAr48fXHgP9/2shJqp3WsZxUMuCSlvdMktbsUfuVi+923Lvzi39uHsT4MdK9pYGsGPL7RArZKMJlghRwyvPxUwU5GNTAfwQ4EmEWN6dE9gr3UwW4KsHWCPYRcaAHzb8B2PHERTNLBODlUsKIOZiOY0gRbI9gYkyCCDfrEfdjwOdihjWAVWDwGgjl1sNc6GAe6FWxRgMUJNgDlThPsORYcQbVDs3dbwV5hkeOWkvrBIdTATE2wOILDkxrYEcHGCbZJsAjBYgQLa2A/E1nAYMCqRHolycju/bs2kdr/7N8axNvkAsEGgS4BBtQ2gMeTLWBrBKsTrJTXwDoIdjqsgXkIlhRgNjWmR3cI9koHuyHANgn2AHKpBcwbgy0buQjGMVHBODlUsLwO5iBYowm2QbBRJkEEC3nFjfOgDvYW6bQAK8HiMhHMroO90cH8F8GWBdg+wYJQbjXBXmDB1at2aPZ2K9i8OptSUh9Mig5maYLtIzg4TrDXnIEexMYItkWwMMG2CTaigbUmHdHVbfzcvUP87FfZR7ajUyM63A3+6bu3w1MZf/n/a2A+McFz2GuCDQAdAsyAWkzG4/EWsA2CVQlWKWpgbQQrDGpgLoIdSjpYHx7dIti8DjZJsLktzNnvc8ltAXNvw5YLXwRjl1UwTg4VLKeDuQhWa4LFCDbMJIhgAx4HwQZ0sEWkMzqYQ4BZdbAFHcx7EWxVgCUI1gtlugk2hwVPt9qh2ZutYG/V9Tol9cDU0MFsTbAEggNjBHvDGehCbJRgOwQbIdguwYbeBRNp/V/8MIH/8utaLfFP/moQ82sn+O2/mscw34S9jKKsrDSkQ+6Usj3PPcGHt4dm/Na/3IKhwhtnJ2+cG28IFgTaNjSwbYLxeHEGFiNYmWC1sgbmI1hpQANzvEQ53QTrwaNpgr3VwcYJ9noHc9Z7kGstYM5d2PIjF8G4r6tgnBwqWFYH8xCs0gTbRt0wyCSIYP1u8VNTQR1sGekTHcwmwMw62JIO5tbA1g3Mpgi2JsBSBGN9a0oHq7wmWJfaodkbrWCLakaUkgLsrw7maIKlEAyOEmyBM9CB2DDB4gQbItgewUKXgH0Sx9/82hH+2Jc4qdju/fIQctkjzP9Pp4rDAunvfdJQfusf1qUX6ECto+UHSZdWYa8RzC3AxJLYC/hiGtguwVimOwPbJliRYI2qBuYhWCWogdleoZxpggXwaIpgizrYGMHexDFnvgO50QJm34OtOHQRjDYqGCeHCnasg/kIVmqC7RJsgEkQwYIu8XOJvTrYKtKnOpiFYGkjoAiwFR3MqYFtGphNEWxDgB0RrAvKhA5We4MFT4faodnJFrD5ZR2sE6aKDuZqgh0h2DtMsCXOQBtigwTbJ1iIYAcE678E7Hkcf/fnMng4bUbi1IAf+t9D8BqSmPnr2hXK3/jHdURnZWaJbai1tYCtrMFescPoFWBLBOvmbN7WwPYINnwbz9nXqVsE2yVYnmBKXQNj8rJY69XALPMonzTBOvFogmDLOtgowRb2Mce0XFZawKwHsJVDF8FKOhgnxxmYQrA2ghWaYHsEY39WCdbnFD/5262DrSFd0MFMBOMMUsG8UR3MTrAcnm6B2RTBYgLsmGAdUCI6GGftgqdN7dDseAvY21UdrF0DMxPM0wQ7RrB7kGArnIEWxAYIliBYP8GSy4j23dPBXhHMpoG9iOMX3+Pn95sUh90t/c+/HcDd7gP8v79QgWh/6lfraKwrBPOh5tsl2Cj/lmDRDdhLVhj9AmyFYF3cL3Y1sAOCDd7E87cEm+YA7a2jkPMgK8kamINgjW4NzPQW5dMmWDseRQi2qoMNE2wpgTlpGrL0McF4ThVg5iRs1f53wSYJxskxYmng7bHtHOy0CXZAsF4mQQTrdYifre/SwTaQLulgBhNwomhg7nUdzEqwPJ7uMLBJgm1zwCpZgrWx5K+DKUtY4JWE6NDsWAvY4poO5tfAjATzNcGyCHYNECzKGWhCLEiwFMH6CHa4imjPHR1sHjMxC57cJ9hcHA/tR3jGMtp0qBuxuAfVfAo3h2v4IU8Zv7YGDJ+CYB7UPHsEGyYXwdZjBDPrYFGCdQCOPQ0sybgGbuD5IsGmCHawiQL31CwXGhXMRjClSwMzLKJcaIL58ShMsDUBto3I4AjcKynMKVOQjZ8SjOdUAWY8hK3e9y7YOMH2BJiCt8eWc7CTJliSYN1MggjWYxf/eqVDB4shXdHBWAHDaUMDc27qYGaCFfE0zsDGb2F+V4DlCOaDMqKDsZC74PGoHZrlgfwMbHlDB/NqYBLB2ppgOQQ7ggRb5ww0INZLsCOC9RAsvYZo4BY0sLeY2TbhyT2CvSaY9RjPTATr70Js34NijgNir+N+sYTvsLx2j91/ARdqrgOCDUK05OY2wYw62DrBmM/bDjSwQ8YVnMTzZYJNECy5hQKXqKzZoIFxQBe5mqhgWEa51ATz4tEIwTYE2C4iA0NwR48w15iAbP6MYINQwaQ0bHLPu2BjBDsQYBLeHpvOwTJNsEOCdTEJIli3Tfz7sDYdbBvpmg4mE6xQgwpm39LBjAQr4+k+AxubxvyeAMsTzANlSAczRrHgcakdmh1uAVuN6WBuDUwhWEcTLI9gWy/BNjkDgVg3wY4JFiBYZgPRzmloYIuY2TXgyR2CcVN/aM7imYVgfR2IJTwamJVg5UoLmAM1R5JgTN/Zklu7BJN0sE2CsUJgSWpgacbVO47nqwSLEOxwB4UjG7JWkwZmUpjptmlg8irKlSaYG4+GCBYTYHuIcIVwrx9jrhaBbH1JMJ5TBZiSgQ2BFrCiBsaJ7UgKMCPeHhsIxnFvs6KaboKlCdbBJIhgAav4F5g+HWwX6YYOVidYidICzLqjg0kEq+JpgoGNTmH+QIAVCeaCEtLBzOtY8DjUDs0OtoCtbetgTg2Ms9ve1QQrIujrJtgWZ6CMWBfBsgTrJNhJDNH2KR1sGTN7wJNbBFsgmDGHZzaC9bQhlvJoYGaCVWstYDbUbIcEC5KLYDt7BFN0sC2CeTiAhxpYhnF1j+H5GsHCBEvHUUhZkLVbNDADwcw+Day+hjIr/hqYE49CBNsWYAeI9PbDvZnFXCUMmXdZJgvPqQKscQKboZOf1wQra2DDo3AcCjAz3h5zS1XY8TYzqodNsAzB2pgEEazLIv6Ns0cH20Na0cGqBKvwaQLMHNfBFILV8TTFwIYnMJ8UYGWCOaD062DWTSx4bGqHZgdawDZ2GawAs2tgNYJ1N8HKCPIoUDrd4fDWEesgWI5g7QQ73UbUP6GDrWKGicGTaYItEYzF02cOggVYHTnyaGBGgrFQeA5mQc2SJlgvuQgWPyBYQwfbIZgLlNHAThhX1yiebxBshGCZfRQSJmSdNg0MBLN6NLDqBsqNJpgdj/oJtivAkoh098G9lcNcaQSyc55gPKdKBKudwmZqbwGramCDw3Ck13UwmWDMHdqMqCabYCcE8zEJIlinWfwWAZcOdoC0pINx80etoIEZ93WwBsFkPD1iYIMRzB8KsCrBbFD6dDD7FhY8FrVDs0ylz8BiezqYVQOrEKy3CVZF0NVBsDhnYBWxNoLlCeYnWGEXUW9EB1vDTLKOJ1MEWyGYUsQzF8E6WR059mhgEsFkpQXMhJopQzCm72zJ/STBajpYnGDi4J3RwFgFedwxjOcxgg0R7CSBwr4BWbdDA+NzF+0uDawcQ5l/p4FZ8aiPYHsC7BCRrh64d/KYKwxBdr8lGM+pAqxSgM3ibwGra2ADg3BkNnWwOsGYnbdJqB40wU4J5mESRLAOk/g9HQ4dLMmjlw5WJFjjVAOTEhrYVg3DfcDTYwY2EMZ8WoDVCWaB0kOwVQG2gwWPSe3QbG8L2PaBDma+BKyOoKONYPucgWXEfAQrEsxLsNIeou6wDraBmcMqnkwQLBrHw0YZzzwEa2d1JOvRwHhmug+pBYwQhhOCMX1nSyZY++BSr4GJ4i8vNuUTDazAuNoG8XybYCGCnaYIBoK5NLA6wZwODay4jTIr+BqYGY+4LEsHAiyNSEcA7niRYCGCLRKM51QBVirBZuMNyBmYrIFxf3WcbOlgVYIxLW1TNLABAVYgmItJEMHajeI34dh0sEOkzTpYnmDKCf9HMCWlge1UMNxjwNMsAwuOYD4jwGSCmaAECLYmwOJY8BjUDs1yPzgD203qYMZLwGQEbT6CJTgDi4h5CFYmmJtglQNEnSM6WAwz6TKeRAi2TrBaFc98BPOzOsLzkgrWIJjB2ALGhQKnBOvgnwiWShOspIMlCGbhXcupBlZiXL4BPN8lWD/BCkcEkwnm0cBYNF502zSw/C7KxiaYEY8CBEsKsAwibZ1w75cJ1k+wZYLxnCrAChXYHLwBOQODBtYbhIPbgQZWJhgHqq2hgQUFWIlgDiZBBGsziN81ZdHB0khbdbAcwaSMBtY40sDiJQwHTHiaY2C9Q5g/EWAgmAFKJ8E2BNg+FjxQOzTb1QK2d6iDSZeAAUGLh2ApzsA8Yi6CVQnmJFgtiah9SAfbxkymiCdhgm0SjG/mszaCeVkdyXs0sBrBTOYWMF6byAWCMX1nSx5lCFbQwVIEY5z8LBWME+exJ4jnewTrI1jpmGB1gvk0sDLBvBYNLLeHsrkJJuFRJ8EOBdgJIr52uBNVgvURbJVgbRrYaQ02F29AzsAMGlh3LxyFuA5WJJiVYDUNrFeAVQhm41ZLML8kfpubSQfLIG3XwbIMxCjAxnGvdqyB7Rcw3GnB0zwD6w5h/lSAGQjGb2snWEyAJbDgkdUOzXa0gDGjXFTBlEvADAiaXCjxKt7myCHmIFidYHaCNQ4RtYZ0sF3MnBBs5BY+2iJYScazDoK5WR0pejQwIt63WFvA6nxWiWBM39mSxyc62DjBjghm4O1nSQOrMS5XL54frGKqh5UFHugL+1WCtWlgRYL5TRpY9gBlaxNMwaN2gqUF2CkiHj/cqTrBegi2RjCeUwXYSQM2j70FzKSBdXXDUdr/HFhFA+sWYDWCWZgEEcwH8fsSDTrYCdLMiFSwY4KZBdgY7rHjKljiFMPtNjwtMrCufswXBJiJYDIUP8G2BVgKC5662qHZthYwLhcaWOMSMBOCfOVLp8ecgVnEbATjW1GxEkxJI2ru18H2MHNKsKFpfLRDMOZEz7oI5mR1pOzRwEoE4+XfOViVb2uFYHzt2ZLZUx1sjGDHBONfFisaWINxObrxPLmGqQArC7UcwcoE69DA8gRrN2hgx0kOZBOsgUd+gmUEWAERlxduJmZzhQDBNgjGc6oAyyiw+YhxBmbRwDq64KgkPgdW0sC6BFiDYCYmQQTzSj1KeBg62CnSXKNVsCOCWQXYKO6VchpY6gTD3C+elhlYRx/mSwLMQrA6FC/BdgXYERY8VbVDs74WsMMTHax2CZgFQSb0pdMsZyDBLP3cdwhmJpiUQdTYp4MdYKZAsNAUPooT7NSAZ90Es7M6UvVoYAWCOZwtYGXuhzWCuShDMB4XNLBRgmUJJnPEahqYwrhsXXh+uIGpTlYWGnmCFQnWpYHlCNYJDezoEGVnE6yGR16CnQiwEiLcp9zHIFgnwWIE4zlVgPE6xsbz1TmYTQNr64CjlroEbBqWDgGmEMzAJIhgHkOvEh6UdbAC0twPVLAUwewCbBj3CnkNjEvmsNeFp1UG1taD+YoAsxGsCsVNsD0BdowFT1nt0KynBSx9qoNVLgGzIShbCJbjDCSYqY+ZHcGMBDOeICr16GCsxpcI1j+Bj/YJdmLCs16CMb5Y3aOBnRLM5W4BKzLjbBBMpO8Ey5d0sGGC5QjGVyzX0MC4UjxmgvA8HcNUOysLPDYU9vME69bAsgQLyBoYk5eyuwlWwSMum9KpAKsgYnPCnTUQrB0yLy1NFp5TOUaVQyNsXMLPwRwamK8NDi7P74JNwdImwCSC8bjIySq5jX1KeKCug5WQ9utgPDDCKcAGcY+yKthxGsPMmJ5yVj72BTBfE2AOgpWhOAl2IMCyWPAU1Q7NulrAMgUdrHQJmANBbv6l0zxnIMEMPTw7EYwVhGnuCVEloIMdYoZVkSd9EXyUIFjGgmdBgplZHZE9GtgJwTzeFrA8z3QKwZi+syW5/GlggwTLE6zKvF3RwJhmPza14XlmG1Nc4gyMq7CfI1ivBnZMsJ66BpbIoOxtgpXwyEmwggCrIWKxw50zEcxPsF2C8ZwqwJJm2Lq44Z+BuTQwjw8O5fgSsAneuwowI8FkJkEEc5mCSjhY1cEqSHOPUsH2CeYWYAO4d1LWwLKHGHb68JTlo8eeTsw3BJiLYEUodoIlBVgOC548O5TFrGP0HOykpIMVLgFzIVg1EKzIGUgw1ttqFoIpBLMWEG106mBpzNQI1hPGRykBZtPAuBHF4NHAMgTz+VvAcqyaSARj+s6W5PKogQ0QrEiwMnAsaWCEf8yKwvOTXUx5WVlgJaKwnyVYUAM7IlhfVQPbP0HVLyGgghXwyE6wkgBrIGKywp23EMxLsD2C8ZwqwA6ssHUrOlj2HMzlgUPKXgIWgYVbRNluJlidSRDBnOZ+Jdxb1sFqSHeyAwQzxQnmFWBB3MtUNTAOyLDdh9/h4D1ytWOeCYGz4iFYHoqVYIcCLI8FT44dymHWNnwOdlrRwU51sE2CRXQwD4Ict9JpmTOQYHIn638EaxCMZ5BorV0Hy2CGg/wkMIKPjgSYQwPjYMRYCVDB0gRra28By7IuaSQY42FLVhs6WJBgZYIVgSOjCtawMi7CPz/dwxRXEgPrkhfAUgTrL2tgcVYf2iV0qmCneGQlWEWAKYgYzHAXbQRzE+yAYDynso+VPTtsveyUCpY7B3O44DDmLgELw+ISYFaCVZkEEcxhGVDC3UUdrIF0wIYjghl2GKBfgPUSrI4XXQTjkjRs9eF3+Xp+4PBjXhJgPoLloJgJlhZgRSx4suxQHrOWwXOwQk0HO9HBtggW1sF8CBZlglU5AwlWb2eFnWA1gvGQFq34dbATzHBfedI5hI+OBZhLA1NYHTG1gnW2gB2jcWxGd5CbAFuS1QoNrJdgVYLl2SmzCqbYGZdMjMIBppysLBDwXbCiBrZTgNwpoV0FO8EjM8FqAkxCBEa4yw6COQmWJBifLcB2nbAFazpY/hzM5oDDnL8EbAQWhwCzE6zMJIhgdmtICXfldTAF6R4bjgmGLYK1C7Bugsl40UGwIsFYrf7IJON9mxfzRgHWRrAsFCPBuETdtJex4Dlmh4qYNQ2cg7HgKsCOmPUZVLAdgo3oYG0I5usEq3MGEqzq5x0WwSoEc9cQLXl1sFPMcPl90h7CR1kB5tHAGEiMqfM5WKAF7AjKkRVdQWqxJWVJB+smWJ1gOSBhVcEkJ+OqO/C8lMSUnZUFHpneBctrYFsco4AEP8FETB/w2kVqCDAjIvwMd9VFMDvBDgnGYRRg227YBlglUMGK52Dc4xzW4iVgQ7DYBJiTYEU1CZJstkEl3JHTwLiWC7AsweRNgnUKsC6CMXAe5EbKBDP68A2LjPcsbsyzbuasdBDsmB0i2IkAq2LBc8QOlTFrCJ6DVRgswY6lNG8BBFicYEM6WAeCuSpKrOWpYGUvb4kJViKYt4EolxYNrIAZI8H8/fgoJ8B8GliN1RFbK1hPC1gKUsqOjqBMLoJx9mtgXQSTCZYF9jmwBDO6GVfVhueVQ0xZWVlwGi4By2lgmxUYeiR4CSZiel8imCLAzIjwLXYza50rcCK40wTjOVWAxbywDVJIBSufg1HUwYn+LlgIFosAcxMszySIYFb7kBJmZqaCca0XYDmC1TebYB0EM+CFj2BVgkk+fNMm467JiXmrAOsiGGexQrBTAVbHgifFDlUxi95zsJqkgmWlQ8gq2D7BQjpYF4LZMkrsvApWdKPmI1iBYH4F0VOnDlbCjJlg3j58lBdgbfiYYFO8sY45WsH6WsBYL0w50dZbJxdwyP3FqoJ1oMivS3UemOJOFczsZVxlC54z+50ys7LgNl0CltXA1nme7OObRDAR03sKwfimvTRbEak2+Pc+gnGpZeJmsvCcqoL5CVbQwarnYAYTHM7qJWD9sJgEmJdgOSZBBLM4hpWw71gDy5lVsDzBqmdgbQRjiuqpYKhOMMWHbztk3OZ6MW8XYN0ES0FpEKwgwGQseBLsUB2zcvc5WMOoguWkJOoqWIJg/TpYN4LsaIklKhUs70StjWCEmubGHj2x62AVzHDpeOLuwUdFAdaBTwk2UeJ/w0zrHKy/BWwf5pQbvm6m72xpZnBmFayN91ncW+pHwI6bA0QwP+PiLcVzLm1TRlYWvJZLwI41sGgDln4JTpkTnDHdbRCMpbyXZjsizETdaCMYkxk392wLz6kqWDvBTnWw+jkYbxQc7volYH2wGASYn2BZJkEEMztHlDCXMBUsb1XBihfAfLiTseC1q4KQTLCGD99xybgJK+adAqyXYAkoNYKVBBiw4Nlnh2TM1rvOwViBFmB56UAFG6ykCNang/UieJRHqWbSwHJ21DoKvFB0suJgRDRj1cFqmGFS88QZwDfKcTzIdOEzgkUKx6zwe1AmmCUtwEJnYHOIE8wLTxfTULaMxQ6jCuZDtWFCo54CtrwwCLB2xpXnasJbigkWtA1+2yVgRxrYCstMIe6HBBMx3a4RjKW8l6zORIoVuA0dBONxwc2s2MJzKsFqsU5YBnkDooLJZ2D8I2x8uwXYIsEcZ2A9vH4VYDyAG7gScLJKJteoEnalNLCiXQUrXwDz4BaTiTeOCvpBsJoPsyzu3pDNmHcLsCDB9qEwk3tdEWAGgnH2M1uerXacg0lWFawo7algA5UjgvXoYEEEUzmUGhYNLGtFrauARsaJiYAZ0bRZB2tgxkkweye+WY3jfqYbLwkWZtE45vOgSjCTCjbEhEfCHYK9xg7B/HB1sENsJ6xCSCqYB3V+XqOeALb8MPgMMHcyLuYgc0zTIzwuGdodZ2AnioTbKlhKBXuxwjJTSIKVYCKmm0ycJKsAcyPCpdtt6iIYD+RunjstPKcSrBELwDTIMVXBOBY6WLkuw+KHCrZMMNsZWAAWWYB1sjNHkDlZJaM7rIQdCQ2s7FTBqhfAXJjmeWfBVkGfgWAVHz7xyZisGzHvFWADBItDKRGsJsBMBNthhwyYLbedgxntKlhZ2lXBgpVjggV0sAEEE1mUFJsGxjS81l2AkuZs7bEiypKOBqZgxk0wazu+XY9zqe7Fq6ARIycp3lITgGAGFWwEnxDsFsHeYItg7XC0MX1nO3W42VcB5oLcsBFsn2DtMPAOz8yy02RWxmtjAWEmJIZO1xnYqSLhpgqWUMHmVlhmCkkwE0zEdKNEMLsA8yKSK8Bt6SYYS17uEsF4TiWYEuuBYTCtgxnOwKrVOkztBhVslWCWMzC+kXUBFoDBkILMySoZPGNK2LavgVXdKlj9ApgDUzzvLFkq6DERrMRKQJuM8aqEeb8AGyTYDhSWYV5zAG7aLQTbYodMmC36zsHMThWsKm2rYL2VLME6dbBBBPePUZIcGhgPsrXeAu+XnAj32RFNcvBVMAkzXoLxzPcdOc6lOoh5gg1lEoh1eCATDCpYGM8JNk2wBWwSrJM1Sr46bAWXFw0VzMEbdQfB4gTrhMFFsB7GdVzHGxZwR3ivZgh4zsAKioQbKti+CvZ6hWWmkAQjwURMkwWCOQWYHxHeCLhtvQRjUdnN2qmF51SCSbE+YJB9VMFMZ2D1Mg/FnSYVbI1gpjOwdliqAqwHJkOCzyKY5I0oYUtcA6t7VTD5ApgNEzzvrJgqCFgIVvDxTCZjrKxgvl2ADRNsC8opwfi9N+02gm2yQxbM5j04A7O6VbC6FFPBupnZ2XvbdbBhBONHKBldGlhKQi3IQU06MdLvRPRA0cGMmPETzOjFLPemW5kBvCVYKL2PWJeHGc0hZBUsghcEmyLYEj/dnOLSwsO8aCXeVdVUMKbtDRfBdgjGGewgWB/jOuKxxMoEq8JB7PGdgZUUCZMqGD+XYG9WWGYKSZAIJmIaPyWYO4NX5naEj0/gdgQJxjMXz5EGC8+pBDPG+iEPJnUwyxmYXCyDg6uCbRDMcAbmh6WcRdXeR7B9yJysEnzjSti0o4HJfhWMl0otYBZEMj5EDRV02gh2ynS1S8Yoc+L5TgE2SrBNKCcEkwQYl0/POjtkw2zOdQ7G1FSAydKGCtZVyRPMr4ONIriTQsns0cASCmoDBZgPnBgKuRHda+hgZsy0E0xy4xNDHNOZQSwSrP8wjlg3S0kEq6dlFWyOYBMEW0EU5hRnqOuYXDwC8ja4ooJZYGp4CLZFMC5VNoL1M65UGUu8MAyVWFnoazsDqygSxlWwHRVsYYVlppAEhWAiprETgnkzPJt2QtxquF0DBCvxaMBOWMQ5tQpzLIT64IEOxr1PB0O+CJnjLsBiBGOdTwfzwlLMom7vJ1gcMierJPknlBHDlgaGdhXMcAHMhDDPO+uooN1BsBMfXnfLGGbGNh8QYGMEW4eS8eMN73du2BmMJ8oOOTCbdZyDsZovwCCtqWAdlSLBvDrYGIJbCZSsPg1sv4HaYAHWPSdCQ15Ed2vQwKyY6SSY4uSSF8dUZhjLBOtL7iDW64GJYFVuEQLsNcEiHKsoycypPhgcTN/ZaixblVQwEywNH8E2CcavWwgWYlyJIlacDfQXONP7O87AaoqEMRVsSwVbWnGqYA2CgTGNcowklvLemgMY4tWL2zPIY06BYEw8LDynSlVYY0OoDu7pYI4zMEMuj3qfQwXbJpisg9UP3DDls0w2QgTbgczJKhnaJpUhrvMqmKFTBTNdAONmyPPOplyB30Uwvm1vemUM5ip425OGvTJOsCgTBD8WzDZM2j0EW2GHXPjk2IZhgm0S7D13uwpmkFZVsLZKmWBubBJMPCO4uY+SvU0Di9fQGCbYrhP9I36sb1cwrYLZ8SxQxIcNO15Y4lyqR7FKsJ6DLcSCHlgIVk4bVLA3BAsTbJ2LojnVz1UjBdEaHQEVTPLzzMezU6O+TrB+GEwEG2Jc+3lEOch9vOoxhLrOwBqKxLdPgG2qYCsrLDOFuHQTTMQ0nCZYe4Yx9iCUOITbN4w3hVOCSahbWG1RwUZQHtzVwVxnYKZsDtV+lwq2S7C6DiZzhTHkuJTbhwi2BbmTYMb2KSUkr2tgpoAKZrkABm7qXdiqV+D1ECztw0JQxkCW1yV9aVgrkwRbgXLox5LVhnG7D0ueJTwosup9ZMEgwbYIdtfbyWDYOWkZAszHPcLe68QWwcQzgutxlJwdGthOhRfdBNt2oi/cjs1YCVMqmBMf9xTxsGbFnC3OpXoMawQL7G0iNsDlVAUz4R7BFgg20gA2mXaYUyHO8gS5GE5XjwqmMI12NDoIFiVYiFkYwUYYVzyHdXHPdcLKwlD3GZiiSBgm2HL/Ou/fXIiusMwUklAhmIkxDR4SjOO1bO5D/34S7rZRLBROCGYkWAIaWJhg2xyEmjo+TTDLMROLkEcF2yNYVQfDgZ2lIR6Y7SME24TMySqZOm4o/ez0tgCz9KhgtgtgMkI87+xUeRj0EeyQIAMyKxMFdj4Nc+UGwZagJP1YYcIxxrdkxbOA+8wQX/DWeoBgOwS77Q+oYBZpUQXzVOoEs/O5B+ozgtEdlHgVr4JtlSCNESzmRE+kE1sbBUyoYG582lfEg4oZrx37XKrHsEGwzt11xAZ5p5TjLTDB7hBsiWBDBNvSweqmfXIBUnefCtZgVcPV6CLYCr9piLUGgoUZ104Wm35OggwHaqT3DExSJAwSbLU/yrKVC+srLDOFJJQIJmIaSBIskMGquR998QO4O8awVMgQzEywfR0sQrCYCibGpwlmOzpGccingh0QrKyDGQ5YiySmyR4m2Dr3OYKZO6eVvuqKBmbrU8EcF8Dq6Od5J16uwNlGsKQPK4Myeo9OsRrKwMjBDksLUA78iDptGLV3IOp5g3tFlmYSBvDIyXzOi5vtPSqYTXqrgrkqMsGsiFeT6jOC0S2CdWtgm9xjxkuwbtgRmAhgZ40HWcgE8+Kz/iLul4x440pwqR7jUmhE+zYn3LAA4y0wwW4RbIVgoQarTjpYlZu2aMbefh2sTrBugi0RbAQGmWARxrV1zGOZGZ3pYxjCTOf3NTCjImGAYGv9K5gg2OYKy0whCQWCiZiCBwTryWDNHELPzh7cXeNYKaQJZiVYnGB1HWxDBRPj0wRzpI5QHGnDEMGSBCvqYKYDYh9lYbFHCBaFzMkqWbpuKj3lJQ3M0a+CuWytYFX08byzz3KLvYNgB8wYh2V0p06wNnTCSzsB9gbKHvcaN/csexfWCXaHHXrNSd1LsH2C3ejsU8EcDK7OqggLJwQzY796qD4jGN0kWK8Gtn4K82QF1jUrOqd6EF89QVgF8+NlqIh7LPmIAvMQwbYJ5o+tYHdUgJ2oYNMEixKsv8G6rg5WJp1o5mBIB6sSrJdgCwQLw1An2ATj2jzCTqcV7YdHMEQGzsDMisRqjIKN/iVECLa1wrNdSMIpwURMvXs+SH0ZbJiHENjahbt7krcMhwSzE2yHYLIOtkawhjo+TTBXIoViuAMhgh0eF5HXwSwHRlRTWdjsEwRbgdxPMGvglhIoLmCXYAFXSAXzXAAro4fnnUSeA9hFMHZsfVRGV4KdGzllSUoH2/Vi02vDoL0bmwS7xQ69iXMACJYg2GSgXwVzMbh6hes/C732XiMSTOvEM4LRdYIFVbDt6AnMN2qwrprRPt2H/eUMRiDA2vFqqIg7pwqWfEdcqsewSzDvxhL2xgTYqQo2RbB1k4Q+gu3rYEV5C6JZB4Z0sDLBmEw0waoEm2Jc6ynEA3b4kykYJgbPwKyKhF6CxfoXECbYzkpABTshmIipe9cDqf8EMfMQOje34e69gfVCkmBOgm0RjJ+tgq0SjOdKjo8AWxZg+wkUI13oJ1j6ApiEakKATRFsCXKozPuw7ttKZ/4N9gjW6RlSwXwXwIoIZAaRYlZo7ibYrg+bYzI69tOIhRk4B1sF23azJGfDgL0XWwSbZocWduroIliKYOM9IRXMw+DqFTcsFRPBJKSqGfUZwWiUYAMq2O5KBuabDViX+fbc6kdiMY0hFawT8yO88T1pYKXtmEv1GPYI5l5bwMG4ACuoYBME2zQxcSBYQgfLi/SdzT44ooOxKt4YOAcrE2yacUUT2O9xwnuQgGFq+AzMrkhcVRRs97/BCMHiKz0qWIZgIqaubRek0Cm2zUNoX4/BHbyJzcIBwXiXZdkkmAEa2DLBWIXh+Aiw1RLB4vsoTnSjj2CZC2AKqvx8m32aYAuQh1gtsffcUdpzr3FAsHbfiArWdgEsz/8bxlG2wvVfA9siWFv8ENuREmocbBUs5sROuw1BexA7BJtih5a2qugg2BHBxvqGVDAfg6tXnDBVLARTcFQ9UZ8RjK4QbFAF21tiMnNbgXVRgvdOCKm3h2CeB08xgLcEm87wFrojy6V6DAcEc66+QXJSgJVUsAjBtjgoAYKldLBclek7m3M4rIPlCTZ4DsbanvkW41rZR6LPDffePgzTo2dgTkVCF8F2+19jiGD7K30qWJpgIqaOmAPSUAG75iH4oxtwD9zGVmGPYLzLsqwTzKSDLRKMd3gcHwG2JsB24ihO9TLpM+PkAlgD1bgAu0WwN5BH6pAcvXcVf3YOSYL528IqWMcFsBzaed45Pq5ACmpgOwTz7SSxO1FBhYOtgjFBiHfa0GsfQJxgE+zQymaZt0InOCbYaP+ICtbG4OoVOwwVG8EaOK6eqs8IRpcINqyCHSwcwnyXlfC3Ctz3hnA0nwSLOvCUe7BIsKl0BetdOS7VY0gGeYO8/BqHNwRYRQULE2zHJKGTYEc6WLbM9J3NPRrRwXIEGz4H47WK+Q7jWooj1e+FczcOw62xMzC3IqGDYHv9cwgRLLHSr4IdEkzE1LZhgzRSwp55CN6VNbgH72KnsEsw3mVZogSz6GBvCWZWx0eAbQiwrR0Up4OsTplxegGshuqOALtDsNeQmcVKzr57ivd4Docl7gUdERWs6wJYFv7MGLJHPBsNaGBxgnm2DrA3VeOlow7GBGE/YEO3fRD7BIuwQ9H1Inw4RZbXecOhsArWIQkwdqjiIFgN2WpBfUYwuoCSe1QFS75JwvyeEdb5Bpz3R3D86gB9Aqzah2WCTRyWsNmdR4D9OiSYdfE10jddBKupYCMEi5sktBPsWAc7Lmpg3rEmWJZgo+dgOYLd42cs7OAo5Id9m2B3zsG8ioQ2gh0QrN/sQGolBFtIQpJgIibfmgVSuIID8xDcSwQbvot4YZtg7ZeCifERYDEBtrmF4q0BdBKscAGsguqWALtHsDnIEUByBd9T3EdzSFcknh0mVLDuC2B8PzLjyKUqaAxqYPsEc23u4WC6gQIHWwVbNSPB/7bLPowEwcLs0Ho0D9a7kYMTg0MRFayLwdUrZsgVF+y9FS5VJfUZwegbgo2pYIdveDZ7j1f5r2qwPwgj+3IPPQKs3o9VgkWSBWz1Ftm9MaQJZn77GpnbDoI1VLAhgu2bJPgJltXBjvIamH+8CXZMsLFzsCzB7jOuN1s4Hmrn4BLs3jmYX2HZlWBJgvWxonO0MqSCHRBMxORZNUGK1JA0D8G5QLDRu9gvxAjWeSmYGB8Bti3A1jdRvDOIdoKVLoDRYYtg1vsEewF5wsACc/99xZmaQ6amwNkxhQwH/SLYEdyZSeQTFdSGNbAEwRzru0jeUtTBHpEItmxEqo+zzT6KFMFG2KHNFS47KIFlXgyMTGD5DIyn/4qHYPxataI+ozf6mmDjKlj6zR7BrLC+ZGb6MILcZ7sICDA5hDWChQ9OsRMsc6keQ4ZgxvnXOLnL9DqnqGAhgiVMErwEy+lgqZwG1j7ZBDsi2Pg52DHBHjCu15vIjnTCvEGw+xpYTgfzEOyQYD1mC45XRi6AuZY5mKw2H5qHYH9DsLG7SBQ2CBa4FEyMjwDbFWDRdRTvDcNPsMoFsIIGZn5AsOeQpzgpPAPvK/bEHE4aDdg7pnHyDlgKzswNFAlW0cFSBLNFt3F4hwdHDvaQRDAmCEf9XM/tYzgi2BA7tLWUhQMVFGFFMDzFKkATjOlqxUewAorVmvqM7ugcwSZVsMybXYLZYf2sBPOHE8h/uo1OAcZccYNgI3s8jA9U1aX6hGDSq9c4fc9CMEkF6ydYysQVg2B5HSyR1cA6bzTBUgSbfAfMNreOXDgA4xrBHmhgeR3MRbA0wQJmE7Ir4QtgjkUuV1P8unkI1tcEG7+LVGGNYD2XgonxEWB7AmwliuL9UXgJVrsAdqqBGR8S7BPI0xb8G9EpP1zGlc4vAAAAAElFTkSuQmCC";
     let extracted_text = decode_image_and_extract_text(encoded_image);
     match extracted_text {
-        Some(encrypted_text) => {
+        Ok(encrypted_text) => {
             let key = Some("your_secret_key"); // Replace with your actual key
-            let decrypted_text = decrypts(encrypted_text.as_str(), key.clone());
+            let decrypted_text = decrypts(encrypted_text.as_str(), key);
             match decrypted_text {
-                Some(text) => println!("Decrypted Text:\n{}", text),
-                None => println!("Decryption failed."),
+                Ok(text) => println!("Decrypted Text:\n{}", text),
+                Err(error) => println!("Decryption failed: {}", error),
             }
         }
-        None => println!("Failed to decode the image and extract text."),
+        Err(error) => println!("Failed to decode the image and extract text: {}", error),
     }
   }
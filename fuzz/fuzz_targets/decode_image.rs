@@ -0,0 +1,14 @@
+#![no_main]
+
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use encrypted_images::decryption::images::decode_image_and_extract_text;
+use encrypted_images::decryption::verification::verify_img;
+use encrypted_images::encryption::images::ImageSettings;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  let encoded = STANDARD.encode(data);
+  let _ = decode_image_and_extract_text(&encoded);
+  let _ = verify_img(&encoded);
+  let _ = ImageSettings::from_img(&encoded);
+});
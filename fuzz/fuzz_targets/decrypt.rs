@@ -0,0 +1,23 @@
+#![no_main]
+
+use std::io::Write;
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use encrypted_images::decryption::stream::StreamDecryptor;
+use encrypted_images::decryption::text::{decrypt_bytes, decrypts};
+use encrypted_images::encryption::envelope::read_header;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  // Arbitrary text, and arbitrary bytes encoded the way ciphers are.
+  if let Ok(text) = std::str::from_utf8(data) {
+    let _ = decrypts(text, Some("fuzz"));
+  }
+  let encoded = STANDARD.encode(data);
+  let _ = read_header(&encoded);
+  let _ = decrypt_bytes(&encoded, Some("fuzz"));
+
+  let mut decryptor = StreamDecryptor::new(Vec::new(), Some("fuzz"));
+  if decryptor.write_all(data).is_ok() {
+    let _ = decryptor.finish();
+  }
+});
@@ -0,0 +1,78 @@
+  use crate::encryption::kdf::KdfParams;
+  use crate::encryption::secret::KeyBytes;
+  use crate::error::Result;
+
+  #[cfg(feature = "openssl")]
+  mod openssl;
+  // With both features OpenSSL is used and RustCrypto only compared against it in the tests.
+  #[cfg(all(feature = "rust-crypto", any(test, not(feature = "openssl"))))]
+  mod rust_crypto;
+
+  #[cfg(feature = "openssl")]
+  pub(crate) use self::openssl::Openssl;
+  #[cfg(all(feature = "rust-crypto", any(test, not(feature = "openssl"))))]
+  pub(crate) use self::rust_crypto::RustCrypto;
+
+  /// The backend the crate encrypts with. OpenSSL wins if both features are enabled.
+  #[cfg(feature = "openssl")]
+  pub(crate) type Active = Openssl;
+  #[cfg(all(feature = "rust-crypto", not(feature = "openssl")))]
+  pub(crate) type Active = RustCrypto;
+
+  #[cfg(not(any(feature = "openssl", feature = "rust-crypto")))]
+  compile_error!("encrypted_images needs a crypto backend, enable the `openssl` or the `rust-crypto` feature");
+
+  /// The primitives every cipher, key and signature of the crate is built from.
+  ///
+  /// Each backend implements exactly the same algorithms with the same encodings, so ciphers,
+  /// wrapped keys and signatures made with one backend are byte for byte what the other one
+  /// makes from the same keys, nonces and salts, and open with either. Failures of the
+  /// authenticated ciphers are reported as [`crate::Error::MacMismatch`], invalid padding of
+  /// the CBC cipher as [`crate::Error::InvalidPayload`] and unusable X25519 keys as
+  /// [`crate::Error::InvalidKey`].
+  pub(crate) trait Backend {
+    /// Fills `bytes` from the operating system's secure random number generator. Panics if it
+    /// is unavailable, as nothing can be encrypted safely without it.
+    fn fill_random(bytes: &mut [u8]);
+
+    /// AES-256-GCM, returns `ciphertext || tag[16]`.
+    fn aes_256_gcm_seal(key: &[u8], nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>>;
+
+    /// Opens `ciphertext || tag[16]` sealed by [`Backend::aes_256_gcm_seal`].
+    fn aes_256_gcm_open(key: &[u8], nonce: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>>;
+
+    /// AES-256-GCM-SIV, returns `ciphertext || tag[16]`.
+    fn aes_256_gcm_siv_seal(key: &[u8], nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>>;
+
+    /// Opens `ciphertext || tag[16]` sealed by [`Backend::aes_256_gcm_siv_seal`].
+    fn aes_256_gcm_siv_open(key: &[u8], nonce: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>>;
+
+    /// AES-128-CBC with PKCS#7 padding.
+    fn aes_128_cbc_encrypt(key: &[u8], iv: &[u8], plaintext: &[u8]) -> Result<Vec<u8>>;
+
+    fn aes_128_cbc_decrypt(key: &[u8], iv: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>>;
+
+    /// HMAC-SHA256 over the concatenation of `parts`.
+    fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> Result<Vec<u8>>;
+
+    /// HKDF-SHA256 without salt, expanded to `len` bytes.
+    fn hkdf_sha256(key: &[u8], info: &[u8], len: usize) -> Result<KeyBytes>;
+
+    /// scrypt with the cost of `params`, filling `output`. The caller checks `params` first.
+    fn scrypt(passphrase: &[u8], salt: &[u8], params: &KdfParams, output: &mut [u8]) -> Result<()>;
+
+    /// Public key of a raw 32 byte X25519 private key.
+    fn x25519_public_key(private_key: &[u8]) -> Result<Vec<u8>>;
+
+    /// X25519 shared secret, fails for low order public keys that give an all zero secret.
+    fn x25519(private_key: &[u8], public_key: &[u8]) -> Result<KeyBytes>;
+
+    /// Public key of a raw 32 byte Ed25519 private key.
+    fn ed25519_public_key(private_key: &[u8]) -> Result<Vec<u8>>;
+
+    /// Ed25519 signature of `message`, 64 bytes.
+    fn ed25519_sign(private_key: &[u8], message: &[u8]) -> Result<Vec<u8>>;
+
+    /// Whether `signature` is a valid Ed25519 signature of `message` by `public_key`.
+    fn ed25519_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool;
+  }
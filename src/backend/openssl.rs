@@ -0,0 +1,129 @@
+  use openssl::cipher::Cipher as FetchedCipher;
+  use openssl::cipher_ctx::CipherCtx;
+  use openssl::derive::Deriver;
+  use openssl::hash::MessageDigest;
+  use openssl::md::Md;
+  use openssl::pkcs5::scrypt;
+  use openssl::pkey::{Id, PKey};
+  use openssl::pkey_ctx::PkeyCtx;
+  use openssl::sign::{Signer, Verifier};
+  use openssl::symm::{decrypt, decrypt_aead, encrypt, encrypt_aead, Cipher};
+  use crate::backend::Backend;
+  use crate::encryption::kdf::{KdfParams, MAX_MEMORY};
+  use crate::encryption::secret::KeyBytes;
+  use crate::error::{Error, Result};
+
+  const TAG_LEN: usize = 16;
+
+  /// Primitives from OpenSSL, the default backend.
+  pub(crate) struct Openssl;
+
+  impl Backend for Openssl {
+    fn fill_random(bytes: &mut [u8]) {
+      openssl::rand::rand_bytes(bytes).expect("the OpenSSL random number generator failed");
+    }
+
+    fn aes_256_gcm_seal(key: &[u8], nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+      let mut tag = [0u8; TAG_LEN];
+      let mut sealed = encrypt_aead(Cipher::aes_256_gcm(), key, Some(nonce), aad, plaintext, &mut tag)?;
+      sealed.extend_from_slice(&tag);
+      Ok(sealed)
+    }
+
+    fn aes_256_gcm_open(key: &[u8], nonce: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>> {
+      let split = sealed.len().checked_sub(TAG_LEN).ok_or(Error::MacMismatch)?;
+      let (ciphertext, tag) = sealed.split_at(split);
+      decrypt_aead(Cipher::aes_256_gcm(), key, Some(nonce), aad, ciphertext, tag).map_err(|_| Error::MacMismatch)
+    }
+
+    fn aes_256_gcm_siv_seal(key: &[u8], nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+      let cipher = FetchedCipher::fetch(None, "AES-256-GCM-SIV", None)?;
+      let mut ctx = CipherCtx::new()?;
+      ctx.encrypt_init(Some(&cipher), Some(key), Some(nonce))?;
+      ctx.cipher_update(aad, None)?;
+      let mut sealed = Vec::new();
+      ctx.cipher_update_vec(plaintext, &mut sealed)?;
+      ctx.cipher_final_vec(&mut sealed)?;
+      let mut tag = [0u8; TAG_LEN];
+      ctx.tag(&mut tag)?;
+      sealed.extend_from_slice(&tag);
+      Ok(sealed)
+    }
+
+    fn aes_256_gcm_siv_open(key: &[u8], nonce: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>> {
+      let split = sealed.len().checked_sub(TAG_LEN).ok_or(Error::MacMismatch)?;
+      let (ciphertext, tag) = sealed.split_at(split);
+      let cipher = FetchedCipher::fetch(None, "AES-256-GCM-SIV", None)?;
+      let mut ctx = CipherCtx::new()?;
+      ctx.decrypt_init(Some(&cipher), Some(key), Some(nonce))?;
+      ctx.set_tag(tag)?;
+      ctx.cipher_update(aad, None)?;
+      let mut output = Vec::new();
+      ctx.cipher_update_vec(ciphertext, &mut output)?;
+      ctx.cipher_final_vec(&mut output).map_err(|_| Error::MacMismatch)?;
+      Ok(output)
+    }
+
+    fn aes_128_cbc_encrypt(key: &[u8], iv: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+      Ok(encrypt(Cipher::aes_128_cbc(), key, Some(iv), plaintext)?)
+    }
+
+    fn aes_128_cbc_decrypt(key: &[u8], iv: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>> {
+      decrypt(Cipher::aes_128_cbc(), key, Some(iv), ciphertext).map_err(|_| Error::InvalidPayload)
+    }
+
+    fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> Result<Vec<u8>> {
+      let pkey = PKey::hmac(key)?;
+      let mut signer = Signer::new(MessageDigest::sha256(), &pkey)?;
+      for part in parts {
+        signer.update(part)?;
+      }
+      Ok(signer.sign_to_vec()?)
+    }
+
+    fn hkdf_sha256(key: &[u8], info: &[u8], len: usize) -> Result<KeyBytes> {
+      let mut ctx = PkeyCtx::new_id(Id::HKDF)?;
+      ctx.derive_init()?;
+      ctx.set_hkdf_md(Md::sha256())?;
+      ctx.set_hkdf_key(key)?;
+      ctx.add_hkdf_info(info)?;
+      let mut output = KeyBytes::new(vec![0u8; len]);
+      ctx.derive(Some(&mut output))?;
+      Ok(output)
+    }
+
+    fn scrypt(passphrase: &[u8], salt: &[u8], params: &KdfParams, output: &mut [u8]) -> Result<()> {
+      Ok(scrypt(passphrase, salt, 1u64 << params.log_n, params.r as u64, params.p as u64, 2 * MAX_MEMORY, output)?)
+    }
+
+    fn x25519_public_key(private_key: &[u8]) -> Result<Vec<u8>> {
+      let private_key = PKey::private_key_from_raw_bytes(private_key, Id::X25519).map_err(|_| Error::InvalidKey)?;
+      Ok(private_key.raw_public_key()?)
+    }
+
+    fn x25519(private_key: &[u8], public_key: &[u8]) -> Result<KeyBytes> {
+      let private_key = PKey::private_key_from_raw_bytes(private_key, Id::X25519).map_err(|_| Error::InvalidKey)?;
+      let public_key = PKey::public_key_from_raw_bytes(public_key, Id::X25519).map_err(|_| Error::InvalidKey)?;
+      let mut deriver = Deriver::new(&private_key)?;
+      deriver.set_peer(&public_key)?;
+      deriver.derive_to_vec().map(KeyBytes::new).map_err(|_| Error::InvalidKey)
+    }
+
+    fn ed25519_public_key(private_key: &[u8]) -> Result<Vec<u8>> {
+      let private_key = PKey::private_key_from_raw_bytes(private_key, Id::ED25519).map_err(|_| Error::InvalidKey)?;
+      Ok(private_key.raw_public_key()?)
+    }
+
+    fn ed25519_sign(private_key: &[u8], message: &[u8]) -> Result<Vec<u8>> {
+      let private_key = PKey::private_key_from_raw_bytes(private_key, Id::ED25519).map_err(|_| Error::InvalidKey)?;
+      let mut signer = Signer::new_without_digest(&private_key)?;
+      Ok(signer.sign_oneshot_to_vec(message)?)
+    }
+
+    fn ed25519_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+      let Ok(public_key) = PKey::public_key_from_raw_bytes(public_key, Id::ED25519) else { return false };
+      Verifier::new_without_digest(&public_key)
+        .and_then(|mut verifier| verifier.verify_oneshot(signature, message))
+        .unwrap_or(false)
+    }
+  }
@@ -0,0 +1,116 @@
+  use aes_gcm::{Aes256Gcm, Nonce};
+  use aes_gcm::aead::consts::U12;
+  use aes_gcm::aead::{Aead, KeyInit, Payload};
+  use aes_gcm_siv::Aes256GcmSiv;
+  use cbc::cipher::{block_padding::Pkcs7, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+  use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+  use hkdf::Hkdf;
+  use hmac::{Hmac, Mac};
+  use rand::RngCore;
+  use rand::rngs::OsRng;
+  use sha2::Sha256;
+  use x25519_dalek::{PublicKey, StaticSecret};
+  use crate::backend::Backend;
+  use crate::encryption::kdf::KdfParams;
+  use crate::encryption::secret::KeyBytes;
+  use crate::error::{Error, Result};
+
+  const NONCE_LEN: usize = 12;
+
+  /// Primitives from the pure-Rust RustCrypto crates.
+  pub(crate) struct RustCrypto;
+
+  impl Backend for RustCrypto {
+    fn fill_random(bytes: &mut [u8]) {
+      OsRng.fill_bytes(bytes);
+    }
+
+    fn aes_256_gcm_seal(key: &[u8], nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+      let cipher = Aes256Gcm::new_from_slice(key).map_err(|_| Error::InvalidArgument("key length"))?;
+      cipher.encrypt(nonce_of(nonce)?, Payload { msg: plaintext, aad }).map_err(|_| Error::InvalidArgument("plaintext length"))
+    }
+
+    fn aes_256_gcm_open(key: &[u8], nonce: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>> {
+      let cipher = Aes256Gcm::new_from_slice(key).map_err(|_| Error::InvalidArgument("key length"))?;
+      cipher.decrypt(nonce_of(nonce)?, Payload { msg: sealed, aad }).map_err(|_| Error::MacMismatch)
+    }
+
+    fn aes_256_gcm_siv_seal(key: &[u8], nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+      let cipher = Aes256GcmSiv::new_from_slice(key).map_err(|_| Error::InvalidArgument("key length"))?;
+      cipher.encrypt(nonce_of(nonce)?, Payload { msg: plaintext, aad }).map_err(|_| Error::InvalidArgument("plaintext length"))
+    }
+
+    fn aes_256_gcm_siv_open(key: &[u8], nonce: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>> {
+      let cipher = Aes256GcmSiv::new_from_slice(key).map_err(|_| Error::InvalidArgument("key length"))?;
+      cipher.decrypt(nonce_of(nonce)?, Payload { msg: sealed, aad }).map_err(|_| Error::MacMismatch)
+    }
+
+    fn aes_128_cbc_encrypt(key: &[u8], iv: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+      let cipher = cbc::Encryptor::<aes::Aes128>::new_from_slices(key, iv).map_err(|_| Error::InvalidArgument("key length"))?;
+      Ok(cipher.encrypt_padded_vec_mut::<Pkcs7>(plaintext))
+    }
+
+    fn aes_128_cbc_decrypt(key: &[u8], iv: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>> {
+      let cipher = cbc::Decryptor::<aes::Aes128>::new_from_slices(key, iv).map_err(|_| Error::InvalidArgument("key length"))?;
+      cipher.decrypt_padded_vec_mut::<Pkcs7>(ciphertext).map_err(|_| Error::InvalidPayload)
+    }
+
+    fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> Result<Vec<u8>> {
+      let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(key).map_err(|_| Error::InvalidArgument("key length"))?;
+      for part in parts {
+        mac.update(part);
+      }
+      Ok(mac.finalize().into_bytes().to_vec())
+    }
+
+    fn hkdf_sha256(key: &[u8], info: &[u8], len: usize) -> Result<KeyBytes> {
+      let mut output = KeyBytes::new(vec![0u8; len]);
+      Hkdf::<Sha256>::new(None, key).expand(info, &mut output).map_err(|_| Error::InvalidArgument("key length"))?;
+      Ok(output)
+    }
+
+    fn scrypt(passphrase: &[u8], salt: &[u8], params: &KdfParams, output: &mut [u8]) -> Result<()> {
+      let params = scrypt::Params::new(params.log_n, params.r as u32, params.p as u32, scrypt::Params::RECOMMENDED_LEN)
+        .map_err(|_| Error::InvalidArgument("scrypt parameters"))?;
+      scrypt::scrypt(passphrase, salt, &params, output).map_err(|_| Error::InvalidArgument("key length"))
+    }
+
+    fn x25519_public_key(private_key: &[u8]) -> Result<Vec<u8>> {
+      let private_key = StaticSecret::from(key_of(private_key)?);
+      Ok(PublicKey::from(&private_key).as_bytes().to_vec())
+    }
+
+    fn x25519(private_key: &[u8], public_key: &[u8]) -> Result<KeyBytes> {
+      let private_key = StaticSecret::from(key_of(private_key)?);
+      let shared_secret = private_key.diffie_hellman(&PublicKey::from(key_of(public_key)?));
+      if !shared_secret.was_contributory() {
+        return Err(Error::InvalidKey);
+      }
+      Ok(KeyBytes::new(shared_secret.as_bytes().to_vec()))
+    }
+
+    fn ed25519_public_key(private_key: &[u8]) -> Result<Vec<u8>> {
+      Ok(SigningKey::from_bytes(&key_of(private_key)?).verifying_key().to_bytes().to_vec())
+    }
+
+    fn ed25519_sign(private_key: &[u8], message: &[u8]) -> Result<Vec<u8>> {
+      Ok(SigningKey::from_bytes(&key_of(private_key)?).sign(message).to_bytes().to_vec())
+    }
+
+    fn ed25519_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+      let (Ok(public_key), Ok(signature)) = (key_of(public_key), Signature::from_slice(signature)) else { return false };
+      VerifyingKey::from_bytes(&public_key).map(|public_key| public_key.verify(message, &signature).is_ok()).unwrap_or(false)
+    }
+  }
+
+  fn nonce_of(nonce: &[u8]) -> Result<&Nonce<U12>> {
+    if nonce.len() != NONCE_LEN {
+      return Err(Error::InvalidArgument("nonce length"));
+    }
+    Ok(Nonce::from_slice(nonce))
+  }
+
+  /// X25519 and Ed25519 keys are 32 raw bytes.
+  fn key_of(key: &[u8]) -> Result<[u8; 32]> {
+    key.try_into().map_err(|_| Error::InvalidKey)
+  }
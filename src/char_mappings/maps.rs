@@ -141,9 +141,6 @@
       .iter()
       .cloned()
       .collect();
-      match color_map.get(&(r, g, b)) {
-        Some(&letter) => Some(letter),
-        None => None,
-      }
+      color_map.get(&(r, g, b)).copied()
     }
   }
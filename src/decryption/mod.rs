@@ -1,2 +1,5 @@
 pub mod text;
 pub mod images;
+pub mod stream;
+pub mod verification;
+pub mod shares;
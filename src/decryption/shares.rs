@@ -0,0 +1,59 @@
+  use crate::decryption::images::decode_image_and_extract_text;
+  use crate::decryption::text::decrypt_bytes;
+  use crate::encryption::shares::gf256;
+  use crate::encryption::shares::SHARE_HEADER_LEN;
+  use crate::error::{Error, Result};
+
+  /// Recovers a secret from the images of [`crate::encryption::shares::split_to_imgs`].
+  ///
+  /// Takes any `threshold` or more images of the same split, in any order, and the key they were
+  /// encrypted with. Fails like `decrypts` if an image doesn't decrypt, and with
+  /// [`Error::InvalidShares`] if the images come from different splits, if an index repeats or if
+  /// there are fewer images than the threshold.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::images::ImageSettings;
+  /// use encrypted_images::encryption::shares::split_to_imgs;
+  /// use encrypted_images::decryption::shares::combine_imgs;
+  ///
+  /// let images = split_to_imgs("ThisIsJustaTestString", 3, 5, None, &ImageSettings::default()).unwrap();
+  ///
+  /// assert_eq!(combine_imgs(&[&images[4], &images[1], &images[3]], None).unwrap(), "ThisIsJustaTestString");
+  /// ```
+  pub fn combine_imgs(encoded_images: &[&str], key: Option<&str>) -> Result<String> {
+    let shares = encoded_images.iter()
+      .map(|image| decrypt_bytes(&decode_image_and_extract_text(image)?, key))
+      .collect::<Result<Vec<Vec<u8>>>>()?;
+    String::from_utf8(combine_bytes(&shares)?).map_err(|_| Error::InvalidUtf8)
+  }
+
+  /// Interpolates the shares made by `split_bytes` at 0.
+  pub(crate) fn combine_bytes(shares: &[Vec<u8>]) -> Result<Vec<u8>> {
+    let first = shares.first().ok_or(Error::InvalidShares)?;
+    if first.len() < SHARE_HEADER_LEN {
+      return Err(Error::InvalidShares);
+    }
+    let threshold = first[1] as usize;
+    for (position, share) in shares.iter().enumerate() {
+      let same_split = share.len() == first.len() && share[1..SHARE_HEADER_LEN] == first[1..SHARE_HEADER_LEN];
+      if !same_split || share[0] == 0 || shares[..position].iter().any(|other| other[0] == share[0]) {
+        return Err(Error::InvalidShares);
+      }
+    }
+    if threshold == 0 || shares.len() < threshold {
+      return Err(Error::InvalidShares);
+    }
+    let shares = &shares[..threshold];
+    // Lagrange basis polynomials evaluated at 0
+    let weights: Vec<u8> = shares.iter().map(|share| {
+      shares.iter().filter(|other| other[0] != share[0]).fold(1, |weight, other| {
+        gf256::mul(weight, gf256::mul(other[0], gf256::inv(gf256::add(other[0], share[0]))))
+      })
+    }).collect();
+    let secret = (SHARE_HEADER_LEN..first.len()).map(|position| {
+      shares.iter().zip(&weights).fold(0, |byte, (share, &weight)| gf256::add(byte, gf256::mul(share[position], weight)))
+    }).collect();
+    Ok(secret)
+  }
@@ -0,0 +1,189 @@
+  use std::io::{self, Read, Write};
+  use crate::backend::{Active, Backend};
+  use crate::encryption::envelope::{Header, Suite};
+  use crate::encryption::policy::check_decryption;
+  use crate::encryption::stream::{segment_nonce, NONCE_PREFIX_LEN, SEGMENT_LEN};
+  use crate::encryption::secret::{KeyBytes, SecretKey};
+  use crate::decryption::text::DecryptOptions;
+  use crate::encryption::text::{associated_data, SuiteKeys, COMMITMENT_LEN, DEFAULT_KEY, GCM_TAG_LEN};
+
+  /// Length of the fixed part of the envelope header, up to and including the key block length.
+  const HEADER_PREFIX_LEN: usize = 10;
+  /// Longest possible header, nonce prefix and key commitment, the key block length is 16 bits.
+  const MAX_HEADER_LEN: usize = HEADER_PREFIX_LEN + u16::MAX as usize + NONCE_PREFIX_LEN + COMMITMENT_LEN;
+  const SEGMENT_CIPHERTEXT_LEN: usize = SEGMENT_LEN + GCM_TAG_LEN;
+
+  /// Decrypts a stream written by [`crate::encryption::stream::StreamEncryptor`] with bounded
+  /// memory.
+  ///
+  /// Ciphertext written to the decryptor is buffered one segment at a time, and only plaintext of
+  /// authenticated segments is passed on to the inner writer. Writing fails as soon as a segment
+  /// doesn't authenticate, which covers modified, reordered and dropped segments. Call
+  /// [`StreamDecryptor::finish`] at the end of the input: it fails if the stream was truncated
+  /// before its last segment.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use std::io::Write;
+  /// use encrypted_images::encryption::stream::StreamEncryptor;
+  /// use encrypted_images::decryption::stream::StreamDecryptor;
+  ///
+  /// let key = Some("your_secret_key");
+  /// let mut encryptor = StreamEncryptor::new(Vec::new(), key).unwrap();
+  /// encryptor.write_all(b"ThisIsJustaTestString").unwrap();
+  /// let encrypted = encryptor.finish().unwrap();
+  ///
+  /// let mut decryptor = StreamDecryptor::new(Vec::new(), key);
+  /// decryptor.write_all(&encrypted).unwrap();
+  /// assert_eq!(decryptor.finish().unwrap(), b"ThisIsJustaTestString");
+  /// ```
+  pub struct StreamDecryptor<W: Write> {
+    writer: W,
+    passphrase: SecretKey,
+    aad: Vec<u8>,
+    state: Option<SegmentState>,
+    counter: u32,
+    finished: bool,
+    buffer: Vec<u8>,
+  }
+
+  /// Everything needed to open segments, available once the header has been read.
+  struct SegmentState {
+    key: KeyBytes,
+    associated_data: Vec<u8>,
+    nonce_prefix: Vec<u8>,
+  }
+
+  impl<W: Write> StreamDecryptor<W> {
+    /// Creates a decryptor that writes the plaintext to `writer`.
+    pub fn new(writer: W, key: Option<&str>) -> Self {
+      Self::with_options(writer, key, &DecryptOptions::default())
+    }
+
+    /// Creates a decryptor for a stream encrypted with associated data, see
+    /// [`DecryptOptions::aad`].
+    pub fn with_options(writer: W, key: Option<&str>, options: &DecryptOptions) -> Self {
+      StreamDecryptor {
+        writer,
+        passphrase: SecretKey::from(key.unwrap_or(DEFAULT_KEY)),
+        aad: options.aad.clone(),
+        state: None,
+        counter: 0,
+        finished: false,
+        buffer: Vec::with_capacity(SEGMENT_CIPHERTEXT_LEN + 1),
+      }
+    }
+
+    /// Decrypts the last segment, then returns the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+      if self.state.is_none() {
+        return Err(invalid_data("stream is truncated"));
+      }
+      let last = std::mem::take(&mut self.buffer);
+      self.open_segment(&last, true)?;
+      self.writer.flush()?;
+      Ok(self.writer)
+    }
+
+    /// Reads the header, nonce prefix and key commitment once enough bytes are buffered. A stream
+    /// committed to another key fails here, before any segment is opened.
+    fn read_header(&mut self) -> io::Result<()> {
+      if self.buffer.len() < HEADER_PREFIX_LEN {
+        return Ok(());
+      }
+      let key_len = u16::from_be_bytes([self.buffer[HEADER_PREFIX_LEN - 2], self.buffer[HEADER_PREFIX_LEN - 1]]);
+      let header_len = HEADER_PREFIX_LEN + key_len as usize;
+      if self.buffer.len() < header_len + NONCE_PREFIX_LEN + COMMITMENT_LEN {
+        return Ok(());
+      }
+      let (header, parsed_len) = Header::parse(&self.buffer)?;
+      if header.suite != Suite::Aes256GcmStream || header.flags != 0 || parsed_len != header_len {
+        return Err(invalid_data("unsupported stream header"));
+      }
+      check_decryption(Some(&header), &self.passphrase)?;
+      let keys = SuiteKeys::derive(&self.passphrase, &header)?;
+      let nonce_end = header_len + NONCE_PREFIX_LEN;
+      keys.check_commitment(&self.buffer[nonce_end..])?;
+      let rest = self.buffer.split_off(nonce_end + COMMITMENT_LEN);
+      self.state = Some(SegmentState {
+        key: keys.cipher,
+        associated_data: associated_data(&self.buffer[..header_len], &self.aad),
+        nonce_prefix: self.buffer[header_len..nonce_end].to_vec(),
+      });
+      self.buffer = rest;
+      Ok(())
+    }
+
+    fn open_segment(&mut self, segment: &[u8], last: bool) -> io::Result<()> {
+      let state = self.state.as_ref().ok_or_else(|| invalid_data("stream is truncated"))?;
+      if self.finished || segment.len() < GCM_TAG_LEN {
+        return Err(invalid_data("stream is truncated"));
+      }
+      let nonce = segment_nonce(&state.nonce_prefix, self.counter, last);
+      let plaintext = Active::aes_256_gcm_open(&state.key, &nonce, &state.associated_data, segment)
+        .map_err(|_| invalid_data("stream segment failed authentication"))?;
+      self.writer.write_all(&plaintext)?;
+      self.counter = self.counter.checked_add(1).ok_or_else(|| invalid_data("stream has too many segments"))?;
+      self.finished = last;
+      Ok(())
+    }
+  }
+
+  impl<W: Write> Write for StreamDecryptor<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+      if self.state.is_none() {
+        let take = data.len().min(MAX_HEADER_LEN - self.buffer.len());
+        self.buffer.extend_from_slice(&data[..take]);
+        self.read_header()?;
+        return Ok(take);
+      }
+      // A full segment is only opened once more data follows it, the last one is opened by
+      // `finish`.
+      let take = data.len().min(SEGMENT_CIPHERTEXT_LEN + 1 - self.buffer.len());
+      self.buffer.extend_from_slice(&data[..take]);
+      if self.buffer.len() > SEGMENT_CIPHERTEXT_LEN {
+        let segment: Vec<u8> = self.buffer.drain(..SEGMENT_CIPHERTEXT_LEN).collect();
+        self.open_segment(&segment, false)?;
+      }
+      Ok(take)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+      self.writer.flush()
+    }
+  }
+
+  /// Decrypts everything `reader` yields into `writer` and returns the number of plaintext bytes.
+  ///
+  /// Fails with [`io::ErrorKind::InvalidData`] if the stream was modified, reordered or truncated.
+  /// Plaintext written before the failure came from authenticated segments, but the stream as a
+  /// whole must be treated as invalid.
+  pub fn decrypt_stream<R: Read, W: Write>(mut reader: R, writer: W, key: Option<&str>) -> io::Result<u64> {
+    let mut counter = CountingWriter { inner: writer, count: 0 };
+    let mut decryptor = StreamDecryptor::new(&mut counter, key);
+    io::copy(&mut reader, &mut decryptor)?;
+    decryptor.finish()?;
+    Ok(counter.count)
+  }
+
+  struct CountingWriter<W: Write> {
+    inner: W,
+    count: u64,
+  }
+
+  impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+      let written = self.inner.write(data)?;
+      self.count += written as u64;
+      Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+      self.inner.flush()
+    }
+  }
+
+  fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+  }
@@ -2,6 +2,10 @@
   ///
   /// This function takes an encoded result and an optional decryption key, and attempts to decrypt
   /// the result using AES-128 CBC decryption. It also verifies the integrity of the data using HMAC.
+  /// Results produced by this version start with a versioned envelope header naming the cipher
+  /// suite and the scrypt parameters and salt, and are dispatched to AES-256-GCM, AES-256-GCM-SIV
+  /// or AES-128-CBC with HMAC accordingly. Results without a header are decrypted as the 1.x
+  /// layout with the zero padded key.
   ///
   /// Timing Attack Protection:
   /// The decryption process is designed to protect against timing attacks, ensuring secure
@@ -12,11 +16,14 @@
   ///
   /// * `encoded_result` - The Base64-encoded result to be decrypted.
   /// * `key` - An optional decryption key. If not provided, the default key "welovenfts" is used.
+  ///   Results of [`crate::encryption::text::encrypts_to`] take the recipient's private key PEM
+  ///   instead of a passphrase. A [`crate::encryption::secret::SecretKey`] is passed as
+  ///   `Some(&secret)`.
   ///
   /// # Returns
   ///
-  /// An `Option<String>` containing the decrypted plaintext if successful, or `None` if decryption
-  /// fails or if the HMAC verification fails.
+  /// The decrypted plaintext, or an [`Error`] saying why decryption failed, for example
+  /// [`Error::MacMismatch`] if the key is wrong or the cipher was modified.
   ///
   /// # Examples
   ///
@@ -26,31 +33,243 @@
   /// let encoded_result = "VkdocGMybHpiWGxqYnc9PbUWoPUFfy9Izm1wkCFZ8gSMWr6EUGW6UwYpnaounDkYmLNDjqWyvjcus2atCStKBOJSCnosjApRrcJrm44hatuaJHSYONbHNOmpk3Rja/xH";
   /// let key = "welovenfts";
   /// let decrypted_data = decrypts(encoded_result, Some(key));
-  /// assert!(decrypted_data.is_some());
+  /// assert!(decrypted_data.is_ok());
   /// ```
   use subtle::ConstantTimeEq;
-  use openssl::symm::{decrypt, Cipher};
-  use crate::encryption::text::hmac::calculate_hmac;
+  use crate::backend::{Active, Backend};
+  use crate::encryption::text::hmac::{calculate_hmac, calculate_hmac_over};
+  use crate::encryption::text::{associated_data, legacy_key, open_deterministic, SuiteKeys, CBC_IV_LEN, COMMITMENT_LEN, DEFAULT_KEY, GCM_NONCE_LEN, GCM_TAG_LEN, HMAC_LEN};
+  use crate::encryption::envelope::{is_envelope, Header, Suite, FLAG_DEFLATE, FLAG_METADATA, FLAG_PADDED};
+  use crate::encryption::metadata::{unix_time, Metadata};
+  use crate::encryption::compression::{inflate, DEFAULT_MAX_DECOMPRESSED_LEN};
+  use crate::encryption::padding::unpad;
+  use crate::encryption::policy::check_decryption;
+  use crate::error::{Error, Result};
   use base64::{Engine as _, engine::{self, general_purpose}, alphabet};
   const CUSTOM_ENGINE: engine::GeneralPurpose =
       engine::GeneralPurpose::new(&alphabet::STANDARD, general_purpose::PAD);
-  pub fn decrypts(encoded_result: &str, key: Option<&str>) -> Option<String> {
-    let key = key.unwrap_or("welovenfts");
-    let mut padded_key = key.as_bytes().to_vec();
-    while padded_key.len() < 16 {
-        padded_key.push(b'\0');
+  pub fn decrypts(encoded_result: &str, key: Option<&str>) -> Result<String> {
+    let decrypted_data = decrypt_bytes(encoded_result, key)?;
+    Ok(String::from_utf8_lossy(&decrypted_data).to_string())
+  }
+
+  /// Decrypts an encoded result like [`decrypts`], but refuses plaintext that is not valid UTF-8
+  /// instead of replacing the invalid sequences with `U+FFFD`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::text::{encrypt_bytes, encrypts};
+  /// use encrypted_images::Error;
+  /// use encrypted_images::decryption::text::decrypts_strict;
+  ///
+  /// let key = Some("your_secret_key");
+  /// let text = encrypts("ThisIsJustaTestString", key, None).unwrap();
+  /// let binary = encrypt_bytes(&[0xff, 0xfe, 0xfd], key, None).unwrap();
+  ///
+  /// assert_eq!(decrypts_strict(&text, key).unwrap(), "ThisIsJustaTestString");
+  /// assert!(matches!(decrypts_strict(&binary, key), Err(Error::InvalidUtf8)));
+  /// ```
+  pub fn decrypts_strict(encoded_result: &str, key: Option<&str>) -> Result<String> {
+    String::from_utf8(decrypt_bytes(encoded_result, key)?).map_err(|_| Error::InvalidUtf8)
+  }
+
+  /// Decrypts an encoded result into the exact bytes that were encrypted.
+  ///
+  /// This is the counterpart of [`crate::encryption::text::encrypt_bytes`] and accepts every
+  /// format [`decrypts`] does.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::text::encrypt_bytes;
+  /// use encrypted_images::decryption::text::decrypt_bytes;
+  ///
+  /// let encrypted = encrypt_bytes(&[0u8, 1, 2, 255], None, None).unwrap();
+  /// assert_eq!(decrypt_bytes(&encrypted, None).unwrap(), vec![0u8, 1, 2, 255]);
+  /// ```
+  pub fn decrypt_bytes(encoded_result: &str, key: Option<&str>) -> Result<Vec<u8>> {
+    decrypt_bytes_with(encoded_result, key, &DecryptOptions::default())
+  }
+
+  /// Optional settings for [`decrypts_with`].
+  #[derive(Clone, Debug)]
+  pub struct DecryptOptions {
+    /// Associated data the result was encrypted with, see
+    /// `encryption::text::EncryptOptions::aad`. Decryption fails unless it matches exactly.
+    pub aad: Vec<u8>,
+    /// Largest plaintext a compressed result may decompress to, decryption fails beyond it.
+    /// Defaults to [`DEFAULT_MAX_DECOMPRESSED_LEN`].
+    pub max_decompressed_len: usize,
+    /// The current time in seconds since the Unix epoch, checked against the validity window of
+    /// results carrying `encryption::metadata::Metadata`. The system clock is used if `None`.
+    pub now: Option<u64>,
+    /// Longest encoded result accepted, in bytes of base64. Decryption fails with
+    /// [`Error::TooLarge`] beyond it, before anything is decoded. Defaults to
+    /// [`DEFAULT_MAX_INPUT_LEN`]; larger data can be streamed instead.
+    pub max_input_len: usize,
+  }
+
+  /// Default limit on the length of an encoded result, 64 MiB.
+  pub const DEFAULT_MAX_INPUT_LEN: usize = 64 << 20;
+
+  impl Default for DecryptOptions {
+    fn default() -> Self {
+      DecryptOptions { aad: Vec::new(), max_decompressed_len: DEFAULT_MAX_DECOMPRESSED_LEN, now: None, max_input_len: DEFAULT_MAX_INPUT_LEN }
     }
-    let result_bytes = CUSTOM_ENGINE.decode(encoded_result).ok()?;
+  }
+
+  /// Decrypts an encoded result like [`decrypts`], with additional settings.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::text::{encrypts_with, EncryptOptions};
+  /// use encrypted_images::decryption::text::{decrypts, decrypts_with, DecryptOptions};
+  ///
+  /// let key = Some("your_secret_key");
+  /// let options = EncryptOptions { aad: b"collection: genesis".to_vec(), ..EncryptOptions::default() };
+  /// let encrypted = encrypts_with("ThisIsJustaTestString", key, None, &options).unwrap();
+  ///
+  /// let genesis = DecryptOptions { aad: b"collection: genesis".to_vec(), ..DecryptOptions::default() };
+  /// assert_eq!(decrypts_with(&encrypted, key, &genesis).unwrap(), "ThisIsJustaTestString");
+  /// assert!(decrypts(&encrypted, key).is_err());
+  /// ```
+  pub fn decrypts_with(encoded_result: &str, key: Option<&str>, options: &DecryptOptions) -> Result<String> {
+    let decrypted_data = decrypt_bytes_with(encoded_result, key, options)?;
+    Ok(String::from_utf8_lossy(&decrypted_data).to_string())
+  }
+
+  /// Decrypts an encoded result into bytes like [`decrypt_bytes`], with the settings of
+  /// [`decrypts_with`]. Results in the 1.x layout have no associated data and fail to decrypt
+  /// with any.
+  pub fn decrypt_bytes_with(encoded_result: &str, key: Option<&str>, options: &DecryptOptions) -> Result<Vec<u8>> {
+    decrypt_bytes_with_metadata(encoded_result, key, options).map(|(decrypted_data, _)| decrypted_data)
+  }
+
+  /// Decrypts an encoded result into bytes like [`decrypt_bytes_with`], and also returns the
+  /// metadata embedded with `encryption::text::EncryptOptions::metadata`, if any.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::metadata::Metadata;
+  /// use encrypted_images::encryption::text::{encrypts_with, EncryptOptions};
+  /// use encrypted_images::decryption::text::{decrypt_bytes_with_metadata, DecryptOptions};
+  ///
+  /// let key = Some("your_secret_key");
+  /// let metadata = Metadata::now();
+  /// let options = EncryptOptions { metadata: Some(metadata), ..EncryptOptions::default() };
+  /// let encrypted = encrypts_with("ThisIsJustaTestString", key, None, &options).unwrap();
+  ///
+  /// let (decrypted, embedded) = decrypt_bytes_with_metadata(&encrypted, key, &DecryptOptions::default()).unwrap();
+  /// assert_eq!(decrypted, b"ThisIsJustaTestString");
+  /// assert_eq!(embedded.unwrap(), metadata);
+  /// ```
+  pub fn decrypt_bytes_with_metadata(encoded_result: &str, key: Option<&str>, options: &DecryptOptions) -> Result<(Vec<u8>, Option<Metadata>)> {
+    let key = key.unwrap_or(DEFAULT_KEY);
+    if encoded_result.len() > options.max_input_len {
+      return Err(Error::TooLarge);
+    }
+    let result_bytes = CUSTOM_ENGINE.decode(encoded_result).map_err(|_| Error::InvalidBase64)?;
+    if is_envelope(&result_bytes) {
+      return decrypt_envelope(&result_bytes, key, options);
+    }
+    check_decryption(None, key)?;
+    if !options.aad.is_empty() {
+      return Err(Error::UnsupportedFormat);
+    }
+    if result_bytes.len() < 48 {
+      return Err(Error::Truncated);
+    }
+    let padded_key = legacy_key(key);
     let iv = &result_bytes[..16];
     let hmac = &result_bytes[16..48];
     let ciphertext = &result_bytes[48..];
-    let hmac_calculated = calculate_hmac(ciphertext, &padded_key);
+    let hmac_calculated = calculate_hmac(ciphertext, &padded_key)?;
     if hmac_calculated.ct_eq(hmac).unwrap_u8() == 1 {
-        let cipher = Cipher::aes_128_cbc();
-        let decrypted_data = decrypt(cipher, &padded_key, Some(iv), ciphertext).ok()?;
-        Some(String::from_utf8_lossy(&decrypted_data).to_string())
+        Ok((Active::aes_128_cbc_decrypt(&padded_key, iv, ciphertext)?, None))
     } else {
-        println!("Decryption Failed");
-        None
+        Err(Error::MacMismatch)
+    }
+  }
+
+  /// Reads the envelope header and dispatches to the cipher suite it names. The header bytes and
+  /// the caller's associated data are passed as associated data or covered by the HMAC together
+  /// with the IV, so a modified header or IV or different associated data fail authentication.
+  /// Embedded metadata is checked against the current time.
+  fn decrypt_envelope(bytes: &[u8], key: &str, options: &DecryptOptions) -> Result<(Vec<u8>, Option<Metadata>)> {
+    let (header, header_len) = Header::parse(bytes)?;
+    let (header_bytes, body) = bytes.split_at(header_len);
+    if header.suite == Suite::Aes256GcmStream {
+      return Err(Error::UnsupportedFormat);
+    }
+    check_decryption(Some(&header), key)?;
+    let keys = SuiteKeys::derive(key, &header)?;
+    let decrypted_data = open_body(header.suite, &keys, &associated_data(header_bytes, &options.aad), body)?;
+    let decrypted_data = if header.flags & FLAG_PADDED != 0 {
+      unpad(&decrypted_data)?.to_vec()
+    } else {
+      decrypted_data
+    };
+    let decrypted_data = if header.flags & FLAG_DEFLATE != 0 {
+      inflate(&decrypted_data, options.max_decompressed_len)?
+    } else {
+      decrypted_data
+    };
+    if header.flags & FLAG_METADATA == 0 {
+      return Ok((decrypted_data, None));
+    }
+    let (metadata, plaintext) = Metadata::parse(&decrypted_data)?;
+    metadata.check(options.now.unwrap_or_else(unix_time))?;
+    Ok((plaintext.to_vec(), Some(metadata)))
+  }
+
+  /// Decrypts and authenticates the body of an envelope with the keys of its header.
+  /// `associated_data` is the header, followed by the caller's associated data if there is any.
+  pub(crate) fn open_body(suite: Suite, keys: &SuiteKeys, associated_data: &[u8], body: &[u8]) -> Result<Vec<u8>> {
+    match suite {
+      Suite::Aes256GcmSiv => open_deterministic(&keys.cipher, associated_data, keys.check_commitment(body)?),
+      Suite::Aes256Gcm => match keys.check_commitment(body) {
+        Err(Error::MacMismatch) => open_hidden(keys, associated_data, body),
+        body => open_gcm(keys, associated_data, body?),
+      },
+      Suite::Aes128CbcHmacSha256 => {
+        if body.len() < CBC_IV_LEN + HMAC_LEN {
+          return Err(Error::Truncated);
+        }
+        let (iv, rest) = body.split_at(CBC_IV_LEN);
+        let (hmac, ciphertext) = rest.split_at(HMAC_LEN);
+        let hmac_calculated = calculate_hmac_over(&[associated_data, iv, ciphertext], &keys.mac)?;
+        if hmac_calculated.ct_eq(hmac).unwrap_u8() != 1 {
+          return Err(Error::MacMismatch);
+        }
+        Active::aes_128_cbc_decrypt(&keys.cipher, iv, ciphertext)
+      }
+      Suite::Aes256GcmStream => Err(Error::UnsupportedFormat),
+    }
+  }
+
+  /// Opens an AES-256-GCM body after its key commitment.
+  fn open_gcm(keys: &SuiteKeys, associated_data: &[u8], body: &[u8]) -> Result<Vec<u8>> {
+    if body.len() < GCM_NONCE_LEN + GCM_TAG_LEN {
+      return Err(Error::Truncated);
+    }
+    let (nonce, sealed) = body.split_at(GCM_NONCE_LEN);
+    Active::aes_256_gcm_open(&keys.cipher, nonce, associated_data, sealed)
+  }
+
+  /// Opens the body a deniable result hides in the second half of the ciphertext of an
+  /// AES-256-GCM body, see `encryption::deniable`. Any other body fails at the key commitment, so
+  /// a wrong key costs one more comparison and nothing is decrypted.
+  fn open_hidden(keys: &SuiteKeys, associated_data: &[u8], body: &[u8]) -> Result<Vec<u8>> {
+    let ciphertext = body.get(COMMITMENT_LEN + GCM_NONCE_LEN..body.len().saturating_sub(GCM_TAG_LEN)).unwrap_or_default();
+    if ciphertext.is_empty() || !ciphertext.len().is_multiple_of(2) {
+      return Err(Error::MacMismatch);
+    }
+    let hidden = &ciphertext[ciphertext.len() / 2..];
+    if hidden.len() < COMMITMENT_LEN + GCM_NONCE_LEN + GCM_TAG_LEN {
+      return Err(Error::MacMismatch);
     }
+    open_gcm(keys, associated_data, keys.check_commitment(hidden)?)
   }
@@ -0,0 +1,70 @@
+  use crate::backend::{Active, Backend};
+  use crate::decryption::images::decode_image_and_extract_text;
+  use crate::encryption::chunks::read_text_chunk;
+  use crate::encryption::keys::{signing_public_key_from_pem, signing_public_key_pem};
+  use crate::encryption::signing::{signed_message, ED25519_KEY_LEN, SIGNATURE_KEYWORD, SIGNATURE_LEN};
+  use crate::error::{Error, Result};
+  use base64::{Engine as _, engine::{self, general_purpose}, alphabet};
+  const CUSTOM_ENGINE: engine::GeneralPurpose =
+    engine::GeneralPurpose::new(&alphabet::STANDARD, general_purpose::PAD);
+
+  /// Checks the signature of an image made by `create_signed_img` and returns the signer's
+  /// Ed25519 public key PEM.
+  ///
+  /// Only the image is needed, not the decryption key. Fails with [`Error::MissingSignature`] if
+  /// the image is unsigned and with [`Error::SignatureMismatch`] if the ciphertext it shows
+  /// doesn't match the signature. A valid signature only proves that
+  /// the holder of the returned key signed the ciphertext; compare the key with the one the
+  /// artist published to check authorship.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::images::create_img;
+  /// use encrypted_images::encryption::keys::SigningKeyPair;
+  /// use encrypted_images::encryption::signing::sign_img;
+  /// use encrypted_images::decryption::verification::verify_img;
+  /// use encrypted_images::Error;
+  ///
+  /// let image = create_img("ThisIsCiphertext", "h", "empty", None, None, None, None, None, None).unwrap();
+  /// assert!(matches!(verify_img(&image), Err(Error::MissingSignature)));
+  ///
+  /// let artist = SigningKeyPair::generate().unwrap();
+  /// let signed = sign_img(&image, &artist.private_key_pem().unwrap()).unwrap();
+  /// assert_eq!(verify_img(&signed).unwrap(), artist.public_key_pem().unwrap());
+  /// ```
+  pub fn verify_img(encoded_image: &str) -> Result<String> {
+    let png = CUSTOM_ENGINE.decode(encoded_image).map_err(|_| Error::InvalidBase64)?;
+    let block = read_text_chunk(&png, SIGNATURE_KEYWORD).ok_or(Error::MissingSignature)?;
+    let block = CUSTOM_ENGINE.decode(block).map_err(|_| Error::SignatureMismatch)?;
+    if block.len() != ED25519_KEY_LEN + SIGNATURE_LEN {
+      return Err(Error::SignatureMismatch);
+    }
+    let (public_key, signature) = block.split_at(ED25519_KEY_LEN);
+    let ciphertext = decode_image_and_extract_text(encoded_image)?;
+    if !Active::ed25519_verify(public_key, &signed_message(&ciphertext), signature) {
+      return Err(Error::SignatureMismatch);
+    }
+    Ok(signing_public_key_pem(public_key))
+  }
+
+  /// Whether the image carries a valid signature by the Ed25519 public key PEM `public_key`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::keys::SigningKeyPair;
+  /// use encrypted_images::encryption::signing::create_signed_img;
+  /// use encrypted_images::decryption::verification::verify_img_signed_by;
+  ///
+  /// let artist = SigningKeyPair::generate().unwrap();
+  /// let image = create_signed_img("ThisIsCiphertext", "h", "empty", None, None, None, None, None, None, &artist.private_key_pem().unwrap()).unwrap();
+  ///
+  /// assert!(verify_img_signed_by(&image, &artist.public_key_pem().unwrap()));
+  /// assert!(!verify_img_signed_by(&image, &SigningKeyPair::generate().unwrap().public_key_pem().unwrap()));
+  /// ```
+  pub fn verify_img_signed_by(encoded_image: &str, public_key: &str) -> bool {
+    let expected = signing_public_key_from_pem(public_key);
+    let signer = verify_img(encoded_image).ok().and_then(|pem| signing_public_key_from_pem(&pem));
+    expected.is_some() && signer == expected
+  }
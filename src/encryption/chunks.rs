@@ -0,0 +1,82 @@
+  const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+  const HEADER_CHUNK: &[u8; 4] = b"IHDR";
+  const TEXT_CHUNK: &[u8; 4] = b"tEXt";
+  const END_CHUNK: &[u8; 4] = b"IEND";
+
+  /// Adds a `tEXt` chunk in front of the `IEND` chunk, replacing an earlier chunk with the same
+  /// keyword. Returns `None` if `png` isn't a well formed PNG.
+  pub(crate) fn insert_text_chunk(png: &[u8], keyword: &str, text: &str) -> Option<Vec<u8>> {
+    let chunks = chunks(png)?;
+    let mut output = PNG_SIGNATURE.to_vec();
+    for chunk in chunks {
+      if chunk.chunk_type == END_CHUNK {
+        let data = [keyword.as_bytes(), &[0], text.as_bytes()].concat();
+        output.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        output.extend_from_slice(TEXT_CHUNK);
+        output.extend_from_slice(&data);
+        output.extend_from_slice(&crc(TEXT_CHUNK, &data).to_be_bytes());
+      } else if chunk.chunk_type == TEXT_CHUNK && text_with_keyword(chunk.data, keyword).is_some() {
+        continue;
+      }
+      output.extend_from_slice(chunk.raw);
+    }
+    Some(output)
+  }
+
+  /// Returns the text of the `tEXt` chunk with `keyword`, if there is one with a valid checksum.
+  pub(crate) fn read_text_chunk(png: &[u8], keyword: &str) -> Option<String> {
+    chunks(png)?.into_iter()
+      .filter(|chunk| chunk.chunk_type == TEXT_CHUNK)
+      .find_map(|chunk| text_with_keyword(chunk.data, keyword))
+  }
+
+  /// Width and height from the `IHDR` chunk, read without decoding the image. Returns `None` if
+  /// `png` doesn't start like a PNG.
+  pub(crate) fn dimensions(png: &[u8]) -> Option<(u32, u32)> {
+    let header = png.strip_prefix(&PNG_SIGNATURE)?.get(4..16)?;
+    if &header[..4] != HEADER_CHUNK {
+      return None;
+    }
+    let width = u32::from_be_bytes(header[4..8].try_into().ok()?);
+    let height = u32::from_be_bytes(header[8..12].try_into().ok()?);
+    Some((width, height))
+  }
+
+  fn text_with_keyword(data: &[u8], keyword: &str) -> Option<String> {
+    let text = data.strip_prefix(keyword.as_bytes())?.strip_prefix(&[0])?;
+    String::from_utf8(text.to_vec()).ok()
+  }
+
+  struct Chunk<'a> {
+    chunk_type: &'a [u8],
+    data: &'a [u8],
+    /// The whole chunk including its length and checksum.
+    raw: &'a [u8],
+  }
+
+  /// Splits `png` into its chunks, ending with `IEND`.
+  fn chunks(png: &[u8]) -> Option<Vec<Chunk<'_>>> {
+    let mut rest = png.strip_prefix(&PNG_SIGNATURE)?;
+    let mut chunks = Vec::new();
+    while !rest.is_empty() {
+      let length = u32::from_be_bytes(rest.get(..4)?.try_into().ok()?) as usize;
+      let raw = rest.get(..12usize.checked_add(length)?)?;
+      let (chunk_type, data) = (&raw[4..8], &raw[8..8 + length]);
+      if raw[8 + length..] != crc(chunk_type, data).to_be_bytes() {
+        return None;
+      }
+      chunks.push(Chunk { chunk_type, data, raw });
+      rest = &rest[raw.len()..];
+      if chunk_type == END_CHUNK {
+        return Some(chunks);
+      }
+    }
+    None
+  }
+
+  fn crc(chunk_type: &[u8], data: &[u8]) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(chunk_type);
+    hasher.update(data);
+    hasher.finalize()
+  }
@@ -0,0 +1,57 @@
+  use std::io::{Read, Write};
+  use flate2::Compression as Level;
+  use flate2::read::DeflateDecoder;
+  use flate2::write::DeflateEncoder;
+  use crate::error::{Error, Result};
+
+  /// Compression of the plaintext before it is encrypted.
+  ///
+  /// Image width equals the ciphertext length and images are square, so every byte saved shrinks
+  /// the image quadratically. Compressed data is only kept if it is actually shorter, and the
+  /// envelope header records whether it was. Note that the length of a compressed ciphertext
+  /// depends on the content of the plaintext, so don't compress secrets that are mixed with data
+  /// an attacker controls.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::compression::Compression;
+  /// use encrypted_images::encryption::text::{encrypts, encrypts_with, EncryptOptions};
+  /// use encrypted_images::decryption::text::decrypts;
+  ///
+  /// let key = Some("your_secret_key");
+  /// let input = "ThisIsJustaTestString".repeat(20);
+  /// let options = EncryptOptions { compression: Compression::Deflate, ..EncryptOptions::default() };
+  /// let encrypted = encrypts_with(&input, key, Some("authenticated"), &options).unwrap();
+  ///
+  /// assert!(encrypted.len() < encrypts(&input, key, Some("authenticated")).unwrap().len());
+  /// assert_eq!(decrypts(&encrypted, key).unwrap(), input);
+  /// ```
+  #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+  pub enum Compression {
+    /// Encrypt the plaintext as it is.
+    #[default]
+    None,
+    /// Compress the plaintext with raw deflate first.
+    Deflate,
+  }
+
+  /// Default upper bound for the decompressed plaintext, so a small crafted ciphertext can't
+  /// expand into gigabytes.
+  pub const DEFAULT_MAX_DECOMPRESSED_LEN: usize = 16 * 1024 * 1024;
+
+  pub(crate) fn deflate(input: &[u8]) -> Result<Vec<u8>> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Level::best());
+    encoder.write_all(input)?;
+    Ok(encoder.finish()?)
+  }
+
+  /// Decompresses `input`, fails if it is invalid or inflates to more than `limit` bytes.
+  pub(crate) fn inflate(input: &[u8], limit: usize) -> Result<Vec<u8>> {
+    let mut output = Vec::new();
+    DeflateDecoder::new(input).take(limit as u64 + 1).read_to_end(&mut output).map_err(|_| Error::InvalidPayload)?;
+    if output.len() > limit {
+      return Err(Error::DecompressionLimit);
+    }
+    Ok(output)
+  }
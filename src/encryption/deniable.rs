@@ -0,0 +1,122 @@
+  use crate::backend::{Active, Backend};
+  use crate::encryption::compression::{deflate, Compression};
+  use crate::encryption::envelope::{Header, KeyBlock, Suite, FLAG_DEFLATE, FLAG_METADATA, FLAG_PADDED};
+  use crate::encryption::kdf::SALT_LEN;
+  use crate::encryption::padding::{pad_to, Padding, LENGTH_LEN};
+  use crate::encryption::policy::check_encryption;
+  use crate::encryption::text::{associated_data, generate_random_bytes, seal_gcm, EncryptOptions, SuiteKeys, COMMITMENT_LEN, GCM_NONCE_LEN, GCM_TAG_LEN};
+  use crate::error::{Error, Result};
+  use base64::{Engine as _, engine::{self, general_purpose}, alphabet};
+  const CUSTOM_ENGINE: engine::GeneralPurpose =
+    engine::GeneralPurpose::new(&alphabet::STANDARD, general_purpose::PAD);
+
+  /// A harmless message that a deniable result reveals under its own passphrase.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct Decoy<'a> {
+    /// The decoy message.
+    pub input: &'a str,
+    /// The passphrase that decrypts to the decoy message. Must differ from the real one.
+    pub key: &'a str,
+  }
+
+  /// Encrypts the input text so that it can be denied.
+  ///
+  /// The result is an ordinary padded result of the `authenticated` strength holding the decoy
+  /// message under the decoy passphrase. The real message is encrypted under `key` on its own
+  /// and placed in the second half of the padded ciphertext, where the decoy passphrase decrypts
+  /// it to what looks like the random filler of any padded result. `decrypts` opens whichever
+  /// message the passphrase it is given belongs to, so under pressure the decoy passphrase can be
+  /// handed over.
+  ///
+  /// The header and layout are those of `encrypts_with` with padding, but the padded size has to
+  /// fit the decoy and the real message in half of it each. It is therefore larger than what the
+  /// padding policy gives for the decoy alone, which someone who knows the decoy and the policy
+  /// can notice. Choose a [`Padding::Fixed`] size that all results share to avoid that. Without
+  /// padding in `options`, [`Padding::PowerOfTwo`] is used.
+  ///
+  /// The result goes through `create_img` and `decode_image_and_extract_text` unchanged.
+  /// Rekeying it or adding recipients works like for any other result and keeps only the message
+  /// of the passphrase used.
+  ///
+  /// Fails with [`Error::InvalidArgument`] if the decoy passphrase is the same as `key`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::deniable::{encrypts_deniable, Decoy};
+  /// use encrypted_images::decryption::text::decrypts;
+  ///
+  /// let decoy = Decoy { input: "Meet me at the gallery", key: "holiday photos" };
+  /// let encrypted = encrypts_deniable("ThisIsJustaTestString", "real passphrase", decoy).unwrap();
+  ///
+  /// assert_eq!(decrypts(&encrypted, Some("real passphrase")).unwrap(), "ThisIsJustaTestString");
+  /// assert_eq!(decrypts(&encrypted, Some("holiday photos")).unwrap(), "Meet me at the gallery");
+  /// ```
+  pub fn encrypts_deniable(input: &str, key: &str, decoy: Decoy) -> Result<String> {
+    encrypts_deniable_with(input, key, decoy, &EncryptOptions::default())
+  }
+
+  /// Encrypts the input text like [`encrypts_deniable`], with the settings of
+  /// `encryption::text::encrypts_with`.
+  ///
+  /// Both messages get the same metadata. Compression is used if it makes the decoy shorter, as
+  /// `encrypts_with` would decide for the decoy alone, and then applies to both messages.
+  pub fn encrypts_deniable_with(input: &str, key: &str, decoy: Decoy, options: &EncryptOptions) -> Result<String> {
+    if decoy.key == key {
+      return Err(Error::InvalidArgument("the decoy passphrase must differ from the passphrase"));
+    }
+    let mut header = Header::new(Suite::Aes256Gcm, KeyBlock::Passphrase { kdf: options.kdf, salt: generate_random_bytes(SALT_LEN) });
+    header.flags |= FLAG_PADDED;
+    if options.metadata.is_some() {
+      header.flags |= FLAG_METADATA;
+    }
+    check_encryption(&header, &[key, decoy.key])?;
+    let decoy_message = with_metadata(decoy.input.as_bytes(), options);
+    let message = with_metadata(input.as_bytes(), options);
+    let compressed = match options.compression {
+      Compression::Deflate => Some(deflate(&decoy_message)?).filter(|compressed| compressed.len() < decoy_message.len()),
+      Compression::None => None,
+    };
+    let (decoy_message, message) = match compressed {
+      Some(compressed) => {
+        header.flags |= FLAG_DEFLATE;
+        (compressed, deflate(&message)?)
+      }
+      None => (decoy_message, message),
+    };
+
+    // Each half holds one message, the hidden one as a complete AES-256-GCM body
+    let hidden_overhead = COMMITMENT_LEN + GCM_NONCE_LEN + GCM_TAG_LEN + LENGTH_LEN;
+    let half = (LENGTH_LEN + decoy_message.len()).max(hidden_overhead + message.len());
+    let padding = if options.padding == Padding::None { Padding::PowerOfTwo } else { options.padding };
+    let padded_len = padding.padded_size(2 * half)?.ok_or(Error::InvalidArgument("padding"))?;
+    let padded_len = match padding {
+      _ if padded_len.is_multiple_of(2) => padded_len,
+      Padding::Fixed(_) => return Err(Error::InvalidArgument("deniable results need an even padding size")),
+      _ => padding.padded_size(padded_len + 1)?.ok_or(Error::InvalidArgument("padding"))?,
+    };
+    let half = padded_len / 2;
+
+    let header_bytes = header.to_bytes();
+    let associated_data = associated_data(&header_bytes, &options.aad);
+    let hidden = seal_gcm(&SuiteKeys::derive(key, &header)?, &associated_data, &pad_to(&message, half - (hidden_overhead - LENGTH_LEN), false)?)?;
+    let decoy_keys = SuiteKeys::derive(decoy.key, &header)?;
+    let nonce = generate_random_bytes(GCM_NONCE_LEN);
+    // Sealing zeros gives the keystream of the nonce. That output is never published, only the
+    // seal of the plaintext below, which makes the second half of its ciphertext the hidden body.
+    let keystream = Active::aes_256_gcm_seal(&decoy_keys.cipher, &nonce, &[], &vec![0; padded_len])?;
+    let mut plaintext = pad_to(&decoy_message, padded_len, false)?;
+    for ((byte, hidden), keystream) in plaintext[half..].iter_mut().zip(&hidden).zip(&keystream[half..padded_len]) {
+      *byte = hidden ^ keystream;
+    }
+    let sealed = Active::aes_256_gcm_seal(&decoy_keys.cipher, &nonce, &associated_data, &plaintext)?;
+    Ok(CUSTOM_ENGINE.encode([header_bytes, decoy_keys.commitment.clone(), nonce, sealed].concat()))
+  }
+
+  /// Puts the metadata of `options`, if any, in front of a message.
+  fn with_metadata(input: &[u8], options: &EncryptOptions) -> Vec<u8> {
+    match options.metadata {
+      Some(metadata) => [&metadata.to_bytes()[..], input].concat(),
+      None => input.to_vec(),
+    }
+  }
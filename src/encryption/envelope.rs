@@ -0,0 +1,283 @@
+  use crate::encryption::kdf::{KdfParams, SALT_LEN};
+  use crate::encryption::keys::{WRAPPED_KEY_LEN, X25519_KEY_LEN};
+  use crate::encryption::recipients::MAX_PASSPHRASE_RECIPIENTS;
+  use crate::error::{Error, Result};
+  use base64::{Engine as _, engine::{self, general_purpose}, alphabet};
+  const CUSTOM_ENGINE: engine::GeneralPurpose =
+    engine::GeneralPurpose::new(&alphabet::STANDARD, general_purpose::PAD);
+
+  /// First bytes of every envelope. The leading byte is not a base64 character, so an envelope can
+  /// never be mistaken for the 1.x layout, which starts with the base64 encoded IV.
+  pub const MAGIC: [u8; 4] = [0xE1, b'I', b'M', b'G'];
+  /// Envelope version written by this crate. The unversioned 1.x layout counts as version 1.
+  pub const VERSION: u8 = 2;
+  /// Identifier of the scrypt key derivation block.
+  const KDF_SCRYPT: u8 = 1;
+  /// Identifier of the recipient slots block.
+  const KEY_RECIPIENTS: u8 = 2;
+  /// Identifiers of the recipient slot types.
+  const SLOT_PASSPHRASE: u8 = 1;
+  const SLOT_X25519: u8 = 2;
+  const PASSPHRASE_BLOCK_LEN: usize = 3 + SALT_LEN;
+
+  /// Cipher suite of an envelope, which also fixes the layout of the body after the header.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub enum Suite {
+    /// AES-128-CBC with HMAC-SHA256, body `iv[16] || hmac[32] || ciphertext`.
+    Aes128CbcHmacSha256,
+    /// AES-256-GCM with a random 96-bit nonce, body `commitment[32] || nonce[12] || ciphertext
+    /// || tag[16]`.
+    Aes256Gcm,
+    /// Deterministic AES-256-GCM-SIV under a fixed nonce, body `commitment[32] || ciphertext ||
+    /// tag[16]`.
+    Aes256GcmSiv,
+    /// Segmented AES-256-GCM for streams, body `nonce_prefix[7] || commitment[32]` followed by
+    /// segments of `ciphertext || tag[16]`. Written by
+    /// [`crate::encryption::stream::StreamEncryptor`].
+    Aes256GcmStream,
+  }
+
+  impl Suite {
+    pub(crate) fn id(self) -> u8 {
+      match self {
+        Suite::Aes128CbcHmacSha256 => 1,
+        Suite::Aes256Gcm => 2,
+        Suite::Aes256GcmSiv => 3,
+        Suite::Aes256GcmStream => 4,
+      }
+    }
+
+    pub(crate) fn from_id(id: u8) -> Option<Suite> {
+      match id {
+        1 => Some(Suite::Aes128CbcHmacSha256),
+        2 => Some(Suite::Aes256Gcm),
+        3 => Some(Suite::Aes256GcmSiv),
+        4 => Some(Suite::Aes256GcmStream),
+        _ => None,
+      }
+    }
+
+    pub(crate) fn key_len(self) -> usize {
+      match self {
+        Suite::Aes128CbcHmacSha256 => 16,
+        Suite::Aes256Gcm | Suite::Aes256GcmSiv | Suite::Aes256GcmStream => 32,
+      }
+    }
+  }
+
+  /// Where the key of an envelope comes from.
+  #[derive(Clone, Debug, PartialEq, Eq)]
+  pub enum KeyBlock {
+    /// The key is derived from a passphrase with scrypt. Block `log_n || r || p || salt[16]`.
+    Passphrase {
+      /// Cost parameters of the passphrase key derivation.
+      kdf: KdfParams,
+      /// Salt of the passphrase key derivation.
+      salt: Vec<u8>,
+    },
+    /// A random content key wrapped once for every recipient. Block `count[1]` followed by
+    /// `count` slots, each `slot_type[1] || slot`.
+    Recipients(Vec<Slot>),
+  }
+
+  /// One wrapped copy of the content key, which a single recipient's credential unwraps.
+  #[derive(Clone, Debug, PartialEq, Eq)]
+  pub enum Slot {
+    /// Wrapped with a key derived from a passphrase. Slot `log_n || r || p || salt[16] ||
+    /// wrapped_key[48]`.
+    Passphrase {
+      /// Cost parameters of the passphrase key derivation.
+      kdf: KdfParams,
+      /// Salt of the passphrase key derivation.
+      salt: Vec<u8>,
+      /// The content key encrypted with AES-256-GCM, followed by its tag.
+      wrapped_key: Vec<u8>,
+    },
+    /// Wrapped for the holder of an X25519 private key. Slot `recipient_public_key[32] ||
+    /// ephemeral_public_key[32] || wrapped_key[48]`.
+    X25519 {
+      /// Public key of the recipient, which identifies the slot.
+      recipient_public_key: Vec<u8>,
+      /// Public half of the one-time key pair the content key was wrapped with.
+      ephemeral_public_key: Vec<u8>,
+      /// The content key encrypted with AES-256-GCM, followed by its tag.
+      wrapped_key: Vec<u8>,
+    },
+  }
+
+  impl KeyBlock {
+    fn id(&self) -> u8 {
+      match self {
+        KeyBlock::Passphrase { .. } => KDF_SCRYPT,
+        KeyBlock::Recipients(_) => KEY_RECIPIENTS,
+      }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+      match self {
+        KeyBlock::Passphrase { kdf, salt } => [&kdf.to_bytes()[..], salt].concat(),
+        KeyBlock::Recipients(slots) => {
+          let mut bytes = vec![slots.len() as u8];
+          for slot in slots {
+            match slot {
+              Slot::Passphrase { kdf, salt, wrapped_key } => {
+                bytes.push(SLOT_PASSPHRASE);
+                bytes.extend_from_slice(&kdf.to_bytes());
+                bytes.extend_from_slice(salt);
+                bytes.extend_from_slice(wrapped_key);
+              }
+              Slot::X25519 { recipient_public_key, ephemeral_public_key, wrapped_key } => {
+                bytes.push(SLOT_X25519);
+                bytes.extend_from_slice(recipient_public_key);
+                bytes.extend_from_slice(ephemeral_public_key);
+                bytes.extend_from_slice(wrapped_key);
+              }
+            }
+          }
+          bytes
+        }
+      }
+    }
+
+    fn parse(id: u8, block: &[u8]) -> Result<KeyBlock> {
+      match id {
+        KDF_SCRYPT if block.len() == PASSPHRASE_BLOCK_LEN => Ok(KeyBlock::Passphrase {
+          kdf: KdfParams::from_bytes(block).ok_or(Error::UnsupportedFormat)?,
+          salt: block[3..].to_vec(),
+        }),
+        KEY_RECIPIENTS => {
+          let (&count, mut rest) = block.split_first().ok_or(Error::Truncated)?;
+          let mut slots = Vec::with_capacity(count as usize);
+          for _ in 0..count {
+            let (&slot_type, slot) = rest.split_first().ok_or(Error::Truncated)?;
+            let (slot, slot_len) = match slot_type {
+              SLOT_PASSPHRASE => {
+                let slot = slot.get(..PASSPHRASE_BLOCK_LEN + WRAPPED_KEY_LEN).ok_or(Error::Truncated)?;
+                (Slot::Passphrase {
+                  kdf: KdfParams::from_bytes(slot).ok_or(Error::UnsupportedFormat)?,
+                  salt: slot[3..PASSPHRASE_BLOCK_LEN].to_vec(),
+                  wrapped_key: slot[PASSPHRASE_BLOCK_LEN..].to_vec(),
+                }, slot.len())
+              }
+              SLOT_X25519 => {
+                let slot = slot.get(..2 * X25519_KEY_LEN + WRAPPED_KEY_LEN).ok_or(Error::Truncated)?;
+                (Slot::X25519 {
+                  recipient_public_key: slot[..X25519_KEY_LEN].to_vec(),
+                  ephemeral_public_key: slot[X25519_KEY_LEN..2 * X25519_KEY_LEN].to_vec(),
+                  wrapped_key: slot[2 * X25519_KEY_LEN..].to_vec(),
+                }, slot.len())
+              }
+              _ => return Err(Error::UnsupportedFormat),
+            };
+            slots.push(slot);
+            rest = &rest[1 + slot_len..];
+          }
+          if slots.is_empty() || !rest.is_empty() {
+            return Err(Error::UnsupportedFormat);
+          }
+          // Every passphrase slot may be tried with scrypt, so their number is bounded as well
+          if slots.iter().filter(|slot| matches!(slot, Slot::Passphrase { .. })).count() > MAX_PASSPHRASE_RECIPIENTS {
+            return Err(Error::UnsupportedFormat);
+          }
+          Ok(KeyBlock::Recipients(slots))
+        }
+        _ => Err(Error::UnsupportedFormat),
+      }
+    }
+  }
+
+  /// The self describing header written in front of every ciphertext.
+  ///
+  /// Layout: `magic[4] || version[1] || suite[1] || flags[1] || key_type[1] || key_len[2] ||
+  /// key[key_len]`, where `key_len` is big endian and the key block is described by
+  /// [`KeyBlock`]. The whole header is
+  /// authenticated together with the body.
+  #[derive(Clone, Debug, PartialEq, Eq)]
+  pub struct Header {
+    /// Envelope version, currently always [`VERSION`].
+    pub version: u8,
+    /// Cipher suite of the body.
+    pub suite: Suite,
+    /// Feature flags, [`FLAG_DEFLATE`], [`FLAG_METADATA`] and [`FLAG_PADDED`]. Unknown flags are
+    /// rejected.
+    pub flags: u8,
+    /// How the key of the body is obtained.
+    pub key: KeyBlock,
+  }
+
+  /// The plaintext was compressed with raw deflate before it was encrypted.
+  pub const FLAG_DEFLATE: u8 = 0x01;
+  /// The plaintext starts with encoded [`crate::encryption::metadata::Metadata`].
+  pub const FLAG_METADATA: u8 = 0x02;
+  /// The plaintext was padded as described by [`crate::encryption::padding::Padding`] after any
+  /// compression.
+  pub const FLAG_PADDED: u8 = 0x04;
+  /// Flags understood by this version of the crate.
+  const KNOWN_FLAGS: u8 = FLAG_DEFLATE | FLAG_METADATA | FLAG_PADDED;
+
+  impl Header {
+    pub(crate) fn new(suite: Suite, key: KeyBlock) -> Header {
+      Header { version: VERSION, suite, flags: 0, key }
+    }
+
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+      let block = self.key.to_bytes();
+      let mut bytes = MAGIC.to_vec();
+      bytes.push(self.version);
+      bytes.push(self.suite.id());
+      bytes.push(self.flags);
+      bytes.push(self.key.id());
+      bytes.extend_from_slice(&(block.len() as u16).to_be_bytes());
+      bytes.extend_from_slice(&block);
+      bytes
+    }
+
+    /// Parses the header at the start of `bytes` and returns it with its length in bytes.
+    pub(crate) fn parse(bytes: &[u8]) -> Result<(Header, usize)> {
+      let rest = bytes.strip_prefix(&MAGIC).ok_or(Error::UnsupportedFormat)?;
+      let (&version, rest) = rest.split_first().ok_or(Error::Truncated)?;
+      if version != VERSION {
+        return Err(Error::UnsupportedFormat);
+      }
+      let (&suite, rest) = rest.split_first().ok_or(Error::Truncated)?;
+      let suite = Suite::from_id(suite).ok_or(Error::UnsupportedFormat)?;
+      let (&flags, rest) = rest.split_first().ok_or(Error::Truncated)?;
+      if flags & !KNOWN_FLAGS != 0 {
+        return Err(Error::UnsupportedFormat);
+      }
+      let (&key_id, rest) = rest.split_first().ok_or(Error::Truncated)?;
+      let key_len = rest.get(..2).ok_or(Error::Truncated)?;
+      let key_len = u16::from_be_bytes([key_len[0], key_len[1]]) as usize;
+      let rest = &rest[2..];
+      let block = rest.get(..key_len).ok_or(Error::Truncated)?;
+      let key = KeyBlock::parse(key_id, block)?;
+      let header_len = bytes.len() - rest.len() + block.len();
+      Ok((Header { version, suite, flags, key }, header_len))
+    }
+  }
+
+  pub(crate) fn is_envelope(bytes: &[u8]) -> bool {
+    bytes.starts_with(&MAGIC)
+  }
+
+  /// Reads the envelope header of an encrypted text without decrypting it.
+  ///
+  /// Fails for text that isn't base64, for the unversioned 1.x layout and for headers this
+  /// version of the crate doesn't understand.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::text::encrypts;
+  /// use encrypted_images::encryption::envelope::{read_header, Suite, VERSION};
+  ///
+  /// let encrypted = encrypts("ThisIsJustaTestString", None, Some("authenticated")).unwrap();
+  /// let header = read_header(&encrypted).unwrap();
+  ///
+  /// assert_eq!(header.version, VERSION);
+  /// assert_eq!(header.suite, Suite::Aes256Gcm);
+  /// ```
+  pub fn read_header(encoded_result: &str) -> Result<Header> {
+    let bytes = CUSTOM_ENGINE.decode(encoded_result).map_err(|_| Error::InvalidBase64)?;
+    Header::parse(&bytes).map(|(header, _)| header)
+  }
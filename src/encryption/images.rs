@@ -4,7 +4,8 @@
 /// where the ciphertext is visually represented. You can customize the image's style, overlay
 /// an optional watermark (e.g., Bitcoin, Ethereum, Cardano, or none), adjust color, and more.
 /// The generated image is encoded as a PNG image and then Base64 encoded before being returned
-/// as an `Option<String>`.
+/// as a `String`. The style, watermark and gradient settings are stored in a `tEXt`
+/// chunk of the PNG, so `rekey` can draw the image again for a new ciphertext.
 ///
 /// # Arguments
 ///
@@ -29,8 +30,9 @@
 ///
 /// # Returns
 ///
-/// An `Option<String>` containing the Base64 encoded image if successful, or `None` if there
-/// was an error during image creation or encoding.
+/// The Base64 encoded image, or [`Error::InvalidWatermark`] for a custom watermark that is not an
+/// image or lacks `a`, `w` and `h`, [`Error::TooLarge`] for a ciphertext longer than
+/// [`MAX_IMAGE_DIMENSION`] and [`Error::ImageEncode`] if encoding the PNG fails.
 ///
 /// # Examples
 ///
@@ -41,7 +43,7 @@
 /// let style = "h2";
 /// let watermark = "Bitcoin";
 /// let image_data = create_img(ciphertext, style, watermark, Some(100), Some(134), Some(131), None, None, None);
-/// assert!(image_data.is_some());
+/// assert!(image_data.is_ok());
 /// ```
   use image::{ColorType, DynamicImage, RgbaImage, Rgba, imageops};
   use image::png::PngEncoder;
@@ -49,25 +51,34 @@
   use crate::char_mappings::maps::mappings::get_color;
   use std::io::Cursor;
   use base64::{Engine as _, engine::{self, general_purpose}, alphabet};
+  use crate::encryption::chunks::{insert_text_chunk, read_text_chunk};
+  use crate::decryption::images::decode_image_and_extract_text;
+  use crate::error::{Error, Result};
 
+  /// Returns `Ok(None)` for "empty" and names that aren't built in, which are drawn without a
+  /// watermark.
   fn load_watermark(
       watermark: &str,
       alpha: Option<u8>,
       width: Option<u32>,
       height: Option<u32>,
-  ) -> Option<DynamicImage> {
+  ) -> Result<Option<DynamicImage>> {
       let custom_engine: engine::GeneralPurpose = engine::GeneralPurpose::new(&alphabet::STANDARD, general_purpose::PAD);
       let decoded = custom_engine.decode(watermark);
-      let is_base64 = decoded.is_ok();
-      if is_base64 {
+      if let Ok(decoded) = decoded {
           if let (Some(_a), Some(w), Some(h)) = (alpha, width, height) {
-              let img = match image::load_from_memory(&decoded.ok()?) {
-                  Ok(img) => img,
-                  Err(_) => return None,
-              };
-              Some(img.resize(w, h, image::imageops::FilterType::Nearest))
+              if w > MAX_IMAGE_DIMENSION || h > MAX_IMAGE_DIMENSION {
+                  return Err(Error::TooLarge);
+              }
+              let reader = ImageReader::new(Cursor::new(&decoded)).with_guessed_format().map_err(|_| Error::InvalidWatermark)?;
+              let (original_w, original_h) = reader.into_dimensions().map_err(|_| Error::InvalidWatermark)?;
+              if original_w > MAX_IMAGE_DIMENSION || original_h > MAX_IMAGE_DIMENSION {
+                  return Err(Error::TooLarge);
+              }
+              let img = image::load_from_memory(&decoded).map_err(|_| Error::InvalidWatermark)?;
+              Ok(Some(img.resize(w, h, image::imageops::FilterType::Nearest)))
           } else {
-              None
+              Err(Error::InvalidWatermark)
           }
       } else {
           let watermark: &str = match watermark {
@@ -77,11 +88,11 @@
               "cardano" => "iVBORw0KGgoAAAANSUhEUgAAACAAAAAgCAYAAABzenr0AAAI9HpUWHRSYXcgcHJvZmlsZSB0eXBlIGV4aWYAAHjapVhpsvIwDvyvU8wR4lX2cbxWzQ3m+NOSkxB4wIP3QRGHYGypu7UkNP7330n/wctxjuQDp5hj3PDy2WdbcJK29Sp6NJvXo77s/hO+312n8weLSw6jW19T3Ocf1825wBoKzsJlodT2H+r9D9nv66eHhfaNnFgkVvR9obwv5Oz6wewLlOXWFnPiqwt1rLEfnqT1ITk4Xq4fizx+9wz0esBFZ+1wxm04OmeXAU4+jlzBScbRYBIMxgS5kvTIuyUA5BlO5yvDoimm+qeT7lg5z8zz6/TIlrf7FPcAcjzHp9fJhOesKPSXnX3az+z9dcikL4se0JfPnD1N9RleFB8BddydOlzRM8yr2EK2TgTT4sb4BCzB+s54J6i6gbW+ta3i3Uw2FnRN4003xUwzdGymwURvB1nGibXNOr2YHNtsmxP+vLzNtAxWO3i0rint3tnTFqPb5q2R7pawczeYag0WgxC+f9O3f5hTQsGYLZ1YwS5rBWyYIczJEdPAiJk7qEEBPt6PL+HVgcEgKEuIZABb1xI1mFsmcEq0w8SAcYWL4b4vAIiwdYAxxoEBsGZcMNFsbC0bAyATCCow3TpvKxgwIdgOI613LoKbZGVr/IWNTrXB4jLhOpIZmAguOgY3iDuQ5X2AftgnaKgEF3wIIQYOKeRQoos+hhgjR0mKhR174sCRmRNnLskln0KKiVNKOZVss0PSDDlmzinnXAr2LFi54N8FE0qptrrqa6AaK9dUcy0N8mm+hRYbt9RyK91215E/euzcU8+9DDMgpeFHGHHwSCOPMiG16Wj6GWacPNPMs5ys7bT+eH/BmtlZs8qUTOSTNVxlPpYwkk6CcAbCLHkDxlkokMwmnG3JeG+FOeFsy0h/LlgYGYSzboQxMOiHsWGagzuyi1Fh7p94I/Z3vNm/MkdC3ZfM/eTtGWtdylBTxlYUCqibQ/Th95GKTUWK3Y+RXv1wjrXaMr0UFjNLh4hrj3CihMl15txmGHVWC0FmrrnFbkLPgU310VVYEMLA1CKzfY09Tge2Agzu+B7S2mq6NpBL5QshRevJv4yjlUqp69hnk3wfBrc6g2uzMfessBhA3zkGGUwePFut6wc4WwOQ7t4mUguBpL8BI6gMF9MOzZgFPnAFs72WDopnNgJNYiU8VgNQSFAJRoAxfYYiwAARWFUHyLQAxjvMw/fefgBz+AW36ObXtv2DZ0r/g2dfjBdp0Dtt3EsDBynBLLb7CYnOmVDzZsd/aqWpTkHK8FM3wWl/R7lYYYGGQNHcnIXzxOrUDBDBRUhvMiPbTSn/SsEODOr5C4k6rB14IlK7pR633ZLpyp+AWpKhpRlFjaeFqcgWygRQgCAig6eC8uUlfKCdXSdqXRTrglqHkr2UghAKtxDKwImdgCG2DuB421ylKpOScX3qpFNHoGgpWAmsSmBXAs2AJcVgErYa46opGH5VFcV5p6vPZVXvQaGXqAgkR2LBhGnH1B9QekbXANiueqE3QlGZ4MoSytLJTSWqkSVUkSzJVAvrxgQ+gCJO6BT/qwABgQtl72aJrDzWSx0/VljksNkYoxkc6yAsn5Hpj5nBrCjYYwDitAYBg7lzqH0X2u6wpFfgfYsdvQLvbV69S6sr/dDP/ONVxoBnMxfVYBgbKq9iOh2EXXKfEF91sLgfrk1u450Vy2UZ2oagRHKHNUDSiZQRtSOQLJzFBgNIcfN2J950lxKZx2sY6ON6I/mqyo3AcHWU2hAxHWl9IGYhHrR+CE8EE0o7wqmpApYWFA2U99lhf2PcmqB0TMgDihuStvyS7l5sSAqxVpuu1SZotcnIV9hGVg+l7fBzbkMhOJLdXTqhI59otts02+H7mR3gVFre1KDeQPsDcyAetGhS2uzqZWjh3Ac6rHALHpNG2yYqQsZ/aiizWwRNbaho2gGg0PlV6FYldZNmRSEYkMSKAIwFshgTATu2M872tSE2Xbvr2lHXTrK2d/S2in6REeiWEtRFWKA3gk7KN0AEJJU9si1r0QAlE70jHGWtX2sDtMSVEOLo3nZCJfp+liWhDKdlW3US37pIc1WouCoUk5Qo2a3BL+2j6q2PGtPldpAnA+JhVcGMvSTCgCU4QbVgGsVc99TzbTvaNx2kCKCfR+XLPBSdM1RvkUq3kMTWzvTVKQrZKCZTuwHOuRvoWLqBcXQDgii6HsbtiTpMzz0+Hearw6qZm5drV2mOwQGBU40LbDsgbQ2MuQfGijwEXYRrXbKWiktRuk+/0vrpNu5Bl+isr8JMR0OhwjQizMC5Qph9dIQUQCBV5tSiXaVoS3CtMIMOYPzYjZcOcmoHyeggAZl2kEgiVRsDQztiR/5QmhS01VLG1VJyUE1aVYk2lfUWDqoSeiKTnyL5QCO0RIKe7b1MVuCJUFQjPx0meNx/evzgsFQVeRyStX8cclwRms+cSSvG9pyMPTek25XGqqYx1jTWB24BIQ5pcKY0OPc5GRmZ7lMy9HFLyvJ465dG59Yfk/myP0bue+gNVoeMDFmlQ26avo5SvL1K0/EuT1/TNH2dp48QT2W/GVw1iC5F6PsadClBdKlBZ25uT3Lz09x5aenp957+s5aefutW37Xwe9+hMNMtWuTJxF2wsDDXtH3ZbUKDnl7YRGLDR2G7Osy9Bbt2gNKAcV5d7WoDYfeXzfsFBvpL4/ms76QnjefZiqwi3df9HPOKAC3SOAPF6x5ySYBWFKmDLkg+t7NJMqy1bjgCH+TxUPe+dEW8tO0Fsdhbn1bSD/5HEuyQUcStziU3mJV8JPXsAjTzfV6gP2noCXb07o7n0yZVn4189PBA7/5eJYem0UQR1efodvS+028ztFXdgEEdrt3oPCfhxp/b2dUWkEnSg2vXLPdAcWTtmuG40RyJNJGlIFx1DJzyVcZV23j6AzJPR/qsr/+9rafXff0HwaZQrgYZzehDIyL3Pn/okOm350yfjvTjhvCPT6Podeb58AHkXkTpnx9A7qFCz250//Kcjb57IPLxs9o/PYJUddC/PIK8jm+e1UKe6Bzo/y4fGVakXTc9AAABhWlDQ1BJQ0MgcHJvZmlsZQAAeJx9kT1Iw0AcxV9TxQ8qInYQUchQXbQgKuIoVSyChdJWaNXB5NIvaNKQpLg4Cq4FBz8Wqw4uzro6uAqC4AeIq4uToouU+L+k0CLGg+N+vLv3uHsHCLUSU822CUDVLCMRjYjpzKrY8YouBNCHMQxLzNRjycUUPMfXPXx8vQvzLO9zf44eJWsywCcSzzHdsIg3iGc2LZ3zPnGQFSSF+Jx43KALEj9yXXb5jXPeYYFnBo1UYp44SCzmW1huYVYwVOJp4pCiapQvpF1WOG9xVksV1rgnf2Egq60kuU5zCFEsIYY4RMiooIgSLIRp1UgxkaD9iId/0PHHySWTqwhGjgWUoUJy/OB/8LtbMzc16SYFIkD7i21/jAAdu0C9atvfx7ZdPwH8z8CV1vSXa8DsJ+nVphY6Anq3gYvrpibvAZc7wMCTLhmSI/lpCrkc8H5G35QB+m+B7jW3t8Y+Th+AFHW1fAMcHAKjecpe93h3Z2tv/55p9PcDpKVyu4IiMZUAAA12aVRYdFhNTDpjb20uYWRvYmUueG1wAAAAAAA8P3hwYWNrZXQgYmVnaW49Iu+7vyIgaWQ9Ilc1TTBNcENlaGlIenJlU3pOVGN6a2M5ZCI/Pgo8eDp4bXBtZXRhIHhtbG5zOng9ImFkb2JlOm5zOm1ldGEvIiB4OnhtcHRrPSJYTVAgQ29yZSA0LjQuMC1FeGl2MiI+CiA8cmRmOlJERiB4bWxuczpyZGY9Imh0dHA6Ly93d3cudzMub3JnLzE5OTkvMDIvMjItcmRmLXN5bnRheC1ucyMiPgogIDxyZGY6RGVzY3JpcHRpb24gcmRmOmFib3V0PSIiCiAgICB4bWxuczp4bXBNTT0iaHR0cDovL25zLmFkb2JlLmNvbS94YXAvMS4wL21tLyIKICAgIHhtbG5zOnN0RXZ0PSJodHRwOi8vbnMuYWRvYmUuY29tL3hhcC8xLjAvc1R5cGUvUmVzb3VyY2VFdmVudCMiCiAgICB4bWxuczpkYz0iaHR0cDovL3B1cmwub3JnL2RjL2VsZW1lbnRzLzEuMS8iCiAgICB4bWxuczpHSU1QPSJodHRwOi8vd3d3LmdpbXAub3JnL3htcC8iCiAgICB4bWxuczp0aWZmPSJodHRwOi8vbnMuYWRvYmUuY29tL3RpZmYvMS4wLyIKICAgIHhtbG5zOnhtcD0iaHR0cDovL25zLmFkb2JlLmNvbS94YXAvMS4wLyIKICAgeG1wTU06RG9jdW1lbnRJRD0iZ2ltcDpkb2NpZDpnaW1wOjJkOWJhMWJjLTU5ODUtNGI2YS04NWMxLWNiNDU3MmY1Y2EyOCIKICAgeG1wTU06SW5zdGFuY2VJRD0ieG1wLmlpZDpiMzMzZmM1ZS1kZTJjLTRkNDEtOGYwNy1mMmVlYzgzOWUwNGEiCiAgIHhtcE1NOk9yaWdpbmFsRG9jdW1lbnRJRD0ieG1wLmRpZDplZDFjN2Q0OC01MmEwLTRhY2UtYWI0Ni1mOWQwOTk5ZGE0OGMiCiAgIGRjOkZvcm1hdD0iaW1hZ2UvcG5nIgogICBHSU1QOkFQST0iMi4wIgogICBHSU1QOlBsYXRmb3JtPSJXaW5kb3dzIgogICBHSU1QOlRpbWVTdGFtcD0iMTY5NDU0NTY4Nzg3MTk2NSIKICAgR0lNUDpWZXJzaW9uPSIyLjEwLjMyIgogICB0aWZmOk9yaWVudGF0aW9uPSIxIgogICB4bXA6Q3JlYXRvclRvb2w9IkdJTVAgMi4xMCIKICAgeG1wOk1ldGFkYXRhRGF0ZT0iMjAyMzowOToxMlQxMzowODowNy0wNjowMCIKICAgeG1wOk1vZGlmeURhdGU9IjIwMjM6MDk6MTJUMTM6MDg6MDctMDY6MDAiPgogICA8eG1wTU06SGlzdG9yeT4KICAgIDxyZGY6U2VxPgogICAgIDxyZGY6bGkKICAgICAgc3RFdnQ6YWN0aW9uPSJzYXZlZCIKICAgICAgc3RFdnQ6Y2hhbmdlZD0iLyIKICAgICAgc3RFdnQ6aW5zdGFuY2VJRD0ieG1wLmlpZDoxMGY3MGJmYy04OTJhLTQzNTktOWRhNi1lMTdkMmNkOTNkMTkiCiAgICAgIHN0RXZ0OnNvZnR3YXJlQWdlbnQ9IkdpbXAgMi4xMCAoV2luZG93cykiCiAgICAgIHN0RXZ0OndoZW49IjIwMjMtMDktMTJUMTM6MDg6MDciLz4KICAgIDwvcmRmOlNlcT4KICAgPC94bXBNTTpIaXN0b3J5PgogIDwvcmRmOkRlc2NyaXB0aW9uPgogPC9yZGY6UkRGPgo8L3g6eG1wbWV0YT4KICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAogICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgCiAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAKICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAogICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgCiAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAKICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAogICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgCiAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAKICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAogICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgCiAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAKICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAogICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgCiAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAKICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAogICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgCiAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAKICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAogICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgCiAgICAgICAgICAgICAgICAgICAgICAgICAgIAo8P3hwYWNrZXQgZW5kPSJ3Ij8+P3y/GgAAAAZiS0dEAP8A/wD/oL2nkwAAAAlwSFlzAAALEwAACxMBAJqcGAAAAAd0SU1FB+cJDBMIB8iOXtEAAALnSURBVFjD3ZdLSJVBFMd/hkHeRYty0YMem7BbUNlUO4tKslpENRGCkhGlbcIHEbSSoCh6eF300h4EXVOEySgio9ootEiHiiCDVi7F2mnZQ2zRuTV83jvffbjIDnx8M+c535z/nDkfTGtS5jzKnM7FxYyQAGUo89GjsRaIeuyHUWadL0R+yAItcCq1VJeG2NcD75n+pEwlyvSgzPI0dBXK9KNMZTqu89Ncwn6gBCgGPkigQqBH5BuwekTGUUCJfjw3EP6lA8AWrG53eEslWBRY7OAiDqyX/Ge81a0ocyYD/TqUqctAP4YyLb4UFAGRECc7gN1AG1Y3C28zUA50YXW3x3pOcNfzQgEFR4CzWD2IMhWBvJbJgrsc3j6sNihTDBwFbmJ1X7YgjAmYFgC7gJ0BuU5isw0wwAVgq2BkY7YL6BAHnTJ/l6RQzQrwBuR9B5gL3PYFyMsAQAuBceCwg4HYHzD+PqqPgFtAAVYPTmUhakaZCXmqPXo1jt71yfL7MyfXAWWaBDCJKjaKMlccp0VAbQAXPswkqAZloo6fuzAxhjIlTqy2GcA852hE5JntKVa+4jURmI8741FgDPgi85/AULJtLEjCu+ZsbY0nBdUoMy56TelkNxMQLgG+Cgj3AO1Y3SSyeqACeADckJI8lPsClDkI1AEXsTqOMg3AJUejFvgGuIA7idXn5DaMAY1YfTXby+gQsBqockq1SyulK3JplXODFkqJzvo2PA60Aidk/jwgf+IUqQQ9lvcxoCXsVswLbPk94AdWV3nSsl0w0InVL4RXIl/6EKufemzjwCKs3pSqFI8CI/4uUXcD3eKwAfiO1ZeB3jQwN+gcwwxOgTIRYAVW9zu8vXLpILIBR1YKvMbqz1PVEcWAvkCf1yPt2Vv5skTwcuAZ0DaVPWGv9HkDTio+pfgneCP6Hf9FOx5Jt732tPOFuWBgjXQ2qQK8QhnjsW+U1GWJAatfAvNDft2GPfbL/vks/wIuPeijyMLzBQAAAABJRU5ErkJggg==",
               _ => "", 
           };
-          let decoded =  custom_engine.decode(watermark).ok()?;
+          let Ok(decoded) = custom_engine.decode(watermark) else { return Ok(None) };
           let cursor = Cursor::new(decoded);
-          ImageReader::with_format(cursor, image::ImageFormat::Png)
+          Ok(ImageReader::with_format(cursor, image::ImageFormat::Png)
               .decode()
-              .ok()
+              .ok())
       }
   }
 
@@ -106,17 +117,139 @@
   }
 
 
-pub fn create_img(ciphertext: &str, style: &str, watermark: &str, r: Option<u8>, g: Option<u8>, b: Option<u8>, a: Option<u8>, w: Option<u32>, h: Option<u32>) -> Option<String> {
+  /// Largest width and height of an image in pixels. Images are as wide and as tall as their
+  /// ciphertext is long, and larger ones are neither created nor decoded.
+  pub const MAX_IMAGE_DIMENSION: u32 = 8192;
+  /// Keyword of the PNG `tEXt` chunk holding the settings an image was created with.
+  const SETTINGS_KEYWORD: &str = "encrypted_images settings";
+  const STYLES: [&str; 4] = ["h", "h2", "v", "v2"];
+  const WATERMARKS: [&str; 4] = ["empty", "bitcoin", "ethereum", "cardano"];
+
+  /// The visual settings of [`create_img`], everything but the ciphertext.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::images::{create_img, ImageSettings};
+  ///
+  /// let image = create_img("ThisIsCiphertext", "h2", "empty", Some(10), Some(20), Some(30), None, None, None).unwrap();
+  /// let settings = ImageSettings::from_img(&image).unwrap();
+  ///
+  /// assert_eq!((settings.style.as_str(), settings.r), ("h2", Some(10)));
+  /// ```
+  #[derive(Clone, Debug, PartialEq, Eq)]
+  pub struct ImageSettings {
+    /// Style of the image, see [`create_img`].
+    pub style: String,
+    /// Built-in watermark name or base64 encoded custom watermark.
+    pub watermark: String,
+    /// Red component of the gradient.
+    pub r: Option<u8>,
+    /// Green component of the gradient.
+    pub g: Option<u8>,
+    /// Blue component of the gradient.
+    pub b: Option<u8>,
+    /// Opacity of a custom watermark.
+    pub a: Option<u8>,
+    /// Width of a custom watermark.
+    pub w: Option<u32>,
+    /// Height of a custom watermark.
+    pub h: Option<u32>,
+  }
+
+  impl Default for ImageSettings {
+    /// The `h` style without a watermark and with the default gradient.
+    fn default() -> Self {
+      ImageSettings { style: "h".to_string(), watermark: "empty".to_string(), r: None, g: None, b: None, a: None, w: None, h: None }
+    }
+  }
+
+  impl ImageSettings {
+    /// Reads the settings of an encoded image.
+    ///
+    /// Images created by this version carry their settings. For older images the settings are
+    /// recovered by drawing the ciphertext again with every style and built-in watermark and the
+    /// default gradient, and keeping the settings that reproduce the image exactly. Fails with
+    /// [`Error::ImageDecode`] if none do, for example for older images with a custom gradient or
+    /// watermark.
+    pub fn from_img(encoded_image: &str) -> Result<ImageSettings> {
+      let custom_engine: engine::GeneralPurpose = engine::GeneralPurpose::new(&alphabet::STANDARD, general_purpose::PAD);
+      let png = custom_engine.decode(encoded_image).map_err(|_| Error::InvalidBase64)?;
+      if let Some(settings) = read_text_chunk(&png, SETTINGS_KEYWORD).and_then(|text| ImageSettings::from_text(&text)) {
+        return Ok(settings);
+      }
+      let ciphertext = decode_image_and_extract_text(encoded_image)?;
+      let pixels = image::load_from_memory(&png).map_err(|_| Error::ImageDecode)?.to_rgba8();
+      STYLES.iter()
+        .flat_map(|style| WATERMARKS.iter().map(move |watermark| (style, watermark)))
+        .map(|(style, watermark)| ImageSettings { style: style.to_string(), watermark: watermark.to_string(), ..ImageSettings::default() })
+        .find(|settings| {
+          settings.create_img(&ciphertext).ok()
+            .and_then(|image| custom_engine.decode(image).ok())
+            .and_then(|candidate| image::load_from_memory(&candidate).ok())
+            .map(|candidate| candidate.to_rgba8() == pixels)
+            .unwrap_or(false)
+        })
+        .ok_or(Error::ImageDecode)
+    }
+
+    /// Creates an image of `ciphertext` with these settings, see [`create_img`].
+    pub fn create_img(&self, ciphertext: &str) -> Result<String> {
+      create_img(ciphertext, &self.style, &self.watermark, self.r, self.g, self.b, self.a, self.w, self.h)
+    }
+
+    /// `style;watermark;r;g;b;a;w;h`, with empty fields for `None`. Styles and watermarks that
+    /// `create_img` doesn't know are drawn like `h` and `empty` and stored that way.
+    fn to_text(&self) -> String {
+      let style = if STYLES.contains(&self.style.as_str()) { self.style.as_str() } else { "h" };
+      let watermark = if self.watermark.contains(';') { "empty" } else { self.watermark.as_str() };
+      let field = |value: Option<u32>| value.map(|value| value.to_string()).unwrap_or_default();
+      [
+        style.to_string(),
+        watermark.to_string(),
+        field(self.r.map(u32::from)),
+        field(self.g.map(u32::from)),
+        field(self.b.map(u32::from)),
+        field(self.a.map(u32::from)),
+        field(self.w),
+        field(self.h),
+      ].join(";")
+    }
+
+    fn from_text(text: &str) -> Option<ImageSettings> {
+      let fields: Vec<&str> = text.split(';').collect();
+      let [style, watermark, r, g, b, a, w, h] = fields[..] else { return None };
+      fn field<T: std::str::FromStr>(value: &str) -> Option<Option<T>> {
+        if value.is_empty() { Some(None) } else { value.parse().ok().map(Some) }
+      }
+      Some(ImageSettings {
+        style: style.to_string(),
+        watermark: watermark.to_string(),
+        r: field(r)?,
+        g: field(g)?,
+        b: field(b)?,
+        a: field(a)?,
+        w: field(w)?,
+        h: field(h)?,
+      })
+    }
+  }
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_img(ciphertext: &str, style: &str, watermark: &str, r: Option<u8>, g: Option<u8>, b: Option<u8>, a: Option<u8>, w: Option<u32>, h: Option<u32>) -> Result<String> {
     let custom_engine: engine::GeneralPurpose = engine::GeneralPurpose::new(&alphabet::STANDARD, general_purpose::PAD);
     let r = r.unwrap_or(100);
     let g = g.unwrap_or(134);
     let b = b.unwrap_or(131);
+    if ciphertext.len() > MAX_IMAGE_DIMENSION as usize {
+        return Err(Error::TooLarge);
+    }
     let width = ciphertext.len() as u32;
     let height = width;
     let mut img: RgbaImage = image::ImageBuffer::new(width, height);
     let last_column = ciphertext.chars().last();
     let shifted_ciphertext = if let Some(last) = last_column {
-        last.to_string() + &ciphertext[..width as usize - 1]
+        last.to_string() + &ciphertext[..ciphertext.len() - last.len_utf8()]
     } else {
         ciphertext.to_string()
     };
@@ -141,7 +274,7 @@ pub fn create_img(ciphertext: &str, style: &str, watermark: &str, r: Option<u8>,
                 (color.2 as i32 - (y as i32 + b as i32)).abs().min(255) as u8
             };
             let rgba_color = Rgba([red, green, blue, 255]);
-            img.put_pixel(x as u32, y, rgba_color);
+            img.put_pixel(x, y, rgba_color);
         }
     }
 
@@ -161,11 +294,11 @@ pub fn create_img(ciphertext: &str, style: &str, watermark: &str, r: Option<u8>,
     let mut row_shift = 0;
 
     for y in 0..height {
-      if (y as u32) % offset == 0 {
+      if y.is_multiple_of(offset) {
         row_shift += 1;
       }
       for x in 0..width {
-        if (y as u32) >= row_shift && y != 0 {
+        if y >= row_shift && y != 0 {
             let pixel = *img.get_pixel(x, y - row_shift);
             new_img.put_pixel(x, y, pixel);
         } else {
@@ -192,33 +325,32 @@ pub fn create_img(ciphertext: &str, style: &str, watermark: &str, r: Option<u8>,
 
     let (alpha, center_w, center_h) = if custom_engine.decode(watermark).ok().is_some() {
         if let (Some(a), Some(w), Some(h)) = (a, w, h) {
-            (Some(a), Some(w), Some(h))
+            (a, w, h)
         } else {
-            return None;
+            return Err(Error::InvalidWatermark);
         }
     } else {
-        (Some(0), Some(32), Some(32))
+        (0, 32, 32)
     };
 
-    let watermark_img = load_watermark(watermark, alpha, center_w, center_h);
+    let watermark_img = load_watermark(watermark, Some(alpha), Some(center_w), Some(center_h))?;
     if let Some(watermark_img) = watermark_img {
-        let nw = (width / 2) - (center_w.unwrap() / 2);
-        let nh = (height / 2) - (center_h.unwrap() / 2);
+        let nw = (width / 2).saturating_sub(center_w / 2);
+        let nh = (height / 2).saturating_sub(center_h / 2);
         let mut watermark_img = watermark_img.to_rgba8();
-        adjust_alpha(&mut watermark_img, alpha.unwrap_or(0));
+        adjust_alpha(&mut watermark_img, alpha);
         image::imageops::overlay(&mut new_img, &watermark_img, nw, nh);
     }
 
     let mut buf = Vec::new();
     let encoder = PngEncoder::new(&mut buf);
     let dyn_img: DynamicImage = DynamicImage::ImageRgba8(new_img.clone());
-    if let Err(err) = encoder.encode(&dyn_img.to_rgba8(), width, height, ColorType::Rgba8) {
-      eprintln!("Error encoding image: {:?}", err);
-      return None;
-    }
+    encoder.encode(&dyn_img.to_rgba8(), width, height, ColorType::Rgba8).map_err(|_| Error::ImageEncode)?;
 
+    let settings = ImageSettings { style: style.to_string(), watermark: watermark.to_string(), r: Some(r), g: Some(g), b: Some(b), a, w, h };
+    let buf = insert_text_chunk(&buf, SETTINGS_KEYWORD, &settings.to_text()).ok_or(Error::ImageEncode)?;
     let encoded_image = custom_engine.encode(&buf);
-    Some(encoded_image)
+    Ok(encoded_image)
 }
 
 /*
@@ -0,0 +1,84 @@
+  use crate::backend::{Active, Backend};
+  use crate::encryption::secret::KeyBytes;
+  use crate::error::{Error, Result};
+
+  /// Cost parameters for the scrypt passphrase key derivation.
+  ///
+  /// The parameters and a random salt are stored alongside every ciphertext that uses them, so
+  /// `decrypts` derives the same key from the passphrase without being told the cost. Raising
+  /// `log_n` doubles both the time and the memory an attacker needs for every password guess.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::kdf::KdfParams;
+  ///
+  /// let params = KdfParams { log_n: 16, ..KdfParams::default() };
+  /// assert_eq!(params.r, 8);
+  /// ```
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct KdfParams {
+    /// Base 2 logarithm of the scrypt CPU/memory cost `N`.
+    pub log_n: u8,
+    /// scrypt block size `r`.
+    pub r: u8,
+    /// scrypt parallelization `p`.
+    pub p: u8,
+  }
+
+  impl Default for KdfParams {
+    fn default() -> Self {
+      KdfParams { log_n: 15, r: 8, p: 1 }
+    }
+  }
+
+  pub(crate) const SALT_LEN: usize = 16;
+  /// Upper bound for the scrypt working memory, so a crafted ciphertext can't make `decrypts`
+  /// allocate without limit.
+  pub(crate) const MAX_MEMORY: u64 = 1 << 30;
+  /// Upper bound for scrypt `p`.
+  pub(crate) const MAX_PARALLELISM: u8 = 16;
+  /// Upper bound for the scrypt work, the working memory mixed `p` times, so a crafted
+  /// ciphertext can't keep `decrypts` busy for long either.
+  pub(crate) const MAX_WORK: u64 = 1 << 30;
+
+  impl KdfParams {
+    pub(crate) fn to_bytes(self) -> [u8; 3] {
+      [self.log_n, self.r, self.p]
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<KdfParams> {
+      match bytes {
+        [log_n, r, p, ..] => {
+          let params = KdfParams { log_n: *log_n, r: *r, p: *p };
+          if params.is_valid() { Some(params) } else { None }
+        }
+        _ => None,
+      }
+    }
+
+    fn is_valid(&self) -> bool {
+      self.log_n >= 1 && self.log_n < 32 && self.r >= 1 && (1..=MAX_PARALLELISM).contains(&self.p)
+        && self.memory() <= MAX_MEMORY && self.memory() * self.p as u64 <= MAX_WORK
+    }
+
+    fn memory(&self) -> u64 {
+      128 * self.r as u64 * (1u64 << self.log_n)
+    }
+  }
+
+  /// Stretches a passphrase of any length into `len` key bytes.
+  pub(crate) fn derive_key(passphrase: &str, salt: &[u8], params: &KdfParams, len: usize) -> Result<KeyBytes> {
+    if !params.is_valid() {
+      return Err(Error::InvalidArgument("scrypt parameters"));
+    }
+    let mut key = KeyBytes::new(vec![0u8; len]);
+    Active::scrypt(passphrase.as_bytes(), salt, params, &mut key)?;
+    Ok(key)
+  }
+
+  /// Expands a master key with HKDF-SHA256 into `len` bytes of key material for the purpose named
+  /// by `info`. Different `info` labels give independent keys.
+  pub(crate) fn expand_key(master: &[u8], info: &[u8], len: usize) -> Result<KeyBytes> {
+    Active::hkdf_sha256(master, info, len)
+  }
@@ -0,0 +1,278 @@
+  use std::fs;
+  use std::path::Path;
+  use crate::backend::{Active, Backend};
+  use crate::encryption::envelope::Slot;
+  use crate::encryption::kdf::expand_key;
+  use crate::encryption::secret::{KeyBytes, SecretKey};
+  use crate::encryption::signing::ED25519_KEY_LEN;
+  use crate::encryption::text::{generate_random_bytes, GCM_NONCE_LEN, GCM_TAG_LEN};
+  use crate::error::{Error, Result};
+  use base64::{Engine as _, engine::{self, general_purpose}, alphabet};
+  use zeroize::Zeroizing;
+  const CUSTOM_ENGINE: engine::GeneralPurpose =
+    engine::GeneralPurpose::new(&alphabet::STANDARD, general_purpose::PAD);
+
+  pub(crate) const X25519_KEY_LEN: usize = 32;
+  /// Length of the random content key of a multi-recipient envelope.
+  pub(crate) const CONTENT_KEY_LEN: usize = 32;
+  pub(crate) const WRAPPED_KEY_LEN: usize = CONTENT_KEY_LEN + GCM_TAG_LEN;
+  const WRAP_KEY_INFO: &[u8] = b"encrypted_images v2 x25519 wrap key";
+  /// Every wrap key is derived from a fresh ephemeral key pair or a fresh salt and used once, so
+  /// a fixed nonce is safe.
+  const WRAP_NONCE: [u8; GCM_NONCE_LEN] = [0u8; GCM_NONCE_LEN];
+  /// DER encoding of a PKCS#8 private key and of a SubjectPublicKeyInfo public key of the
+  /// X25519 and Ed25519 algorithms, up to the 32 raw key bytes (RFC 8410).
+  const X25519_PRIVATE_KEY_DER: [u8; 16] = [0x30, 0x2e, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x6e, 0x04, 0x22, 0x04, 0x20];
+  const X25519_PUBLIC_KEY_DER: [u8; 12] = [0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x6e, 0x03, 0x21, 0x00];
+  const ED25519_PRIVATE_KEY_DER: [u8; 16] = [0x30, 0x2e, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x04, 0x22, 0x04, 0x20];
+  const ED25519_PUBLIC_KEY_DER: [u8; 12] = [0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00];
+  const PRIVATE_KEY_LABEL: &str = "PRIVATE KEY";
+  const PUBLIC_KEY_LABEL: &str = "PUBLIC KEY";
+
+  /// An X25519 key pair for encrypting to a recipient.
+  ///
+  /// Share the public key PEM with anyone who should be able to encrypt for you, and keep the
+  /// private key PEM secret: passing it to `decrypts` in place of a passphrase opens everything
+  /// encrypted to the public key.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::keys::KeyPair;
+  /// use encrypted_images::encryption::text::encrypts_to;
+  /// use encrypted_images::decryption::text::decrypts;
+  ///
+  /// let collector = KeyPair::generate().unwrap();
+  /// let encrypted = encrypts_to("ThisIsJustaTestString", &collector.public_key_pem().unwrap()).unwrap();
+  ///
+  /// let private_key = collector.private_key_pem().unwrap();
+  /// assert_eq!(decrypts(&encrypted, Some(&private_key)).unwrap(), "ThisIsJustaTestString");
+  /// ```
+  pub struct KeyPair {
+    private_key: KeyBytes,
+  }
+
+  impl KeyPair {
+    /// Generates a new random key pair.
+    pub fn generate() -> Result<KeyPair> {
+      Ok(KeyPair { private_key: KeyBytes::new(generate_random_bytes(X25519_KEY_LEN)) })
+    }
+
+    /// Reads a key pair from a PKCS#8 private key PEM, fails with [`Error::InvalidKey`] for
+    /// anything but X25519.
+    pub fn from_private_key_pem(pem: &str) -> Result<KeyPair> {
+      Ok(KeyPair { private_key: private_key_from_pem(pem).ok_or(Error::InvalidKey)? })
+    }
+
+    /// The private key as PKCS#8 PEM.
+    pub fn private_key_pem(&self) -> Result<SecretKey> {
+      Ok(private_key_pem(&X25519_PRIVATE_KEY_DER, &self.private_key))
+    }
+
+    /// The public key as SubjectPublicKeyInfo PEM.
+    pub fn public_key_pem(&self) -> Result<String> {
+      Ok(public_key_pem(&X25519_PUBLIC_KEY_DER, &Active::x25519_public_key(&self.private_key)?))
+    }
+
+    /// Writes the private and the public key PEM to two files. On Unix the private key file is
+    /// only readable by its owner.
+    pub fn save<P: AsRef<Path>, Q: AsRef<Path>>(&self, private_key_path: P, public_key_path: Q) -> Result<()> {
+      let private_key = self.private_key_pem()?;
+      let public_key = self.public_key_pem()?;
+      write_private(private_key_path.as_ref(), private_key.as_bytes())?;
+      Ok(fs::write(public_key_path, public_key)?)
+    }
+
+    /// Reads a key pair from a private key PEM file written by [`KeyPair::save`].
+    pub fn load<P: AsRef<Path>>(private_key_path: P) -> Result<KeyPair> {
+      let pem = SecretKey::from(fs::read_to_string(private_key_path)?);
+      KeyPair::from_private_key_pem(&pem)
+    }
+  }
+
+  /// An Ed25519 key pair for signing encrypted images.
+  ///
+  /// Signing proves who created an image, independently of who can decrypt it. Publish the
+  /// public key PEM so collectors can check the signer reported by
+  /// `decryption::verification::verify_img`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::keys::SigningKeyPair;
+  ///
+  /// let artist = SigningKeyPair::generate().unwrap();
+  /// let restored = SigningKeyPair::from_private_key_pem(&artist.private_key_pem().unwrap()).unwrap();
+  ///
+  /// assert_eq!(restored.public_key_pem().unwrap(), artist.public_key_pem().unwrap());
+  /// ```
+  pub struct SigningKeyPair {
+    private_key: KeyBytes,
+  }
+
+  impl SigningKeyPair {
+    /// Generates a new random key pair.
+    pub fn generate() -> Result<SigningKeyPair> {
+      Ok(SigningKeyPair { private_key: KeyBytes::new(generate_random_bytes(ED25519_KEY_LEN)) })
+    }
+
+    /// Reads a key pair from a PKCS#8 private key PEM, fails with [`Error::InvalidKey`] for
+    /// anything but Ed25519.
+    pub fn from_private_key_pem(pem: &str) -> Result<SigningKeyPair> {
+      Ok(SigningKeyPair { private_key: signing_key_from_pem(pem).ok_or(Error::InvalidKey)? })
+    }
+
+    /// The private key as PKCS#8 PEM.
+    pub fn private_key_pem(&self) -> Result<SecretKey> {
+      Ok(private_key_pem(&ED25519_PRIVATE_KEY_DER, &self.private_key))
+    }
+
+    /// The public key as SubjectPublicKeyInfo PEM.
+    pub fn public_key_pem(&self) -> Result<String> {
+      Ok(signing_public_key_pem(&Active::ed25519_public_key(&self.private_key)?))
+    }
+
+    /// Writes the private and the public key PEM to two files, like [`KeyPair::save`].
+    pub fn save<P: AsRef<Path>, Q: AsRef<Path>>(&self, private_key_path: P, public_key_path: Q) -> Result<()> {
+      let private_key = self.private_key_pem()?;
+      let public_key = self.public_key_pem()?;
+      write_private(private_key_path.as_ref(), private_key.as_bytes())?;
+      Ok(fs::write(public_key_path, public_key)?)
+    }
+
+    /// Reads a key pair from a private key PEM file written by [`SigningKeyPair::save`].
+    pub fn load<P: AsRef<Path>>(private_key_path: P) -> Result<SigningKeyPair> {
+      let pem = SecretKey::from(fs::read_to_string(private_key_path)?);
+      SigningKeyPair::from_private_key_pem(&pem)
+    }
+  }
+
+  /// Reads a public key PEM file and checks that it holds an X25519 key.
+  pub fn load_public_key<P: AsRef<Path>>(public_key_path: P) -> Result<String> {
+    let pem = fs::read_to_string(public_key_path)?;
+    public_key_from_pem(&pem).ok_or(Error::InvalidKey)?;
+    Ok(pem)
+  }
+
+  /// Wraps `content_key` for the holder of the private key belonging to `recipient`.
+  pub(crate) fn wrap_for_public_key(recipient: &str, content_key: &[u8]) -> Result<Slot> {
+    wrap_for_x25519(public_key_from_pem(recipient).ok_or(Error::InvalidKey)?, content_key)
+  }
+
+  /// Wraps a content key for the raw X25519 public key of a recipient.
+  pub(crate) fn wrap_for_x25519(recipient_public_key: Vec<u8>, content_key: &[u8]) -> Result<Slot> {
+    let ephemeral = KeyBytes::new(generate_random_bytes(X25519_KEY_LEN));
+    let ephemeral_public_key = Active::x25519_public_key(&ephemeral)?;
+    let wrap_key = x25519_wrap_key(&ephemeral, &recipient_public_key, &ephemeral_public_key, &recipient_public_key)?;
+    let wrapped_key = wrap(&wrap_key, content_key)?;
+    Ok(Slot::X25519 { recipient_public_key, ephemeral_public_key, wrapped_key })
+  }
+
+  /// Recovers the content key of an X25519 slot, fails with [`Error::WrongKey`] if `private_key`
+  /// isn't the slot's recipient.
+  pub(crate) fn unwrap_with_private_key(private_key: &[u8], recipient_public_key: &[u8], ephemeral_public_key: &[u8], wrapped_key: &[u8]) -> Result<KeyBytes> {
+    if Active::x25519_public_key(private_key)? != recipient_public_key {
+      return Err(Error::WrongKey);
+    }
+    let wrap_key = x25519_wrap_key(private_key, ephemeral_public_key, ephemeral_public_key, recipient_public_key)?;
+    unwrap(&wrap_key, wrapped_key)
+  }
+
+  /// Encrypts a content key under a wrap key that is used exactly once.
+  pub(crate) fn wrap(wrap_key: &[u8], content_key: &[u8]) -> Result<Vec<u8>> {
+    Active::aes_256_gcm_seal(wrap_key, &WRAP_NONCE, &[], content_key)
+  }
+
+  /// Fails with [`Error::WrongKey`] if `wrap_key` didn't wrap `wrapped_key`.
+  pub(crate) fn unwrap(wrap_key: &[u8], wrapped_key: &[u8]) -> Result<KeyBytes> {
+    if wrapped_key.len() != WRAPPED_KEY_LEN {
+      return Err(Error::UnsupportedFormat);
+    }
+    Active::aes_256_gcm_open(wrap_key, &WRAP_NONCE, &[], wrapped_key).map(KeyBytes::new).map_err(|_| Error::WrongKey)
+  }
+
+  /// Derives the wrap key from the X25519 shared secret, bound to both public keys.
+  fn x25519_wrap_key(own: &[u8], peer: &[u8], ephemeral_public_key: &[u8], recipient_public_key: &[u8]) -> Result<KeyBytes> {
+    let shared_secret = Active::x25519(own, peer)?;
+    let info = [WRAP_KEY_INFO, ephemeral_public_key, recipient_public_key].concat();
+    expand_key(&shared_secret, &info, CONTENT_KEY_LEN)
+  }
+
+  /// Raw X25519 private key of a PKCS#8 PEM.
+  pub(crate) fn private_key_from_pem(pem: &str) -> Option<KeyBytes> {
+    raw_key(pem, PRIVATE_KEY_LABEL, &X25519_PRIVATE_KEY_DER).map(KeyBytes::new)
+  }
+
+  /// Raw X25519 public key of a SubjectPublicKeyInfo PEM.
+  pub(crate) fn public_key_from_pem(pem: &str) -> Option<Vec<u8>> {
+    raw_key(pem, PUBLIC_KEY_LABEL, &X25519_PUBLIC_KEY_DER)
+  }
+
+  /// Raw Ed25519 private key of a PKCS#8 PEM.
+  pub(crate) fn signing_key_from_pem(pem: &str) -> Option<KeyBytes> {
+    raw_key(pem, PRIVATE_KEY_LABEL, &ED25519_PRIVATE_KEY_DER).map(KeyBytes::new)
+  }
+
+  /// Raw Ed25519 public key of a SubjectPublicKeyInfo PEM.
+  pub(crate) fn signing_public_key_from_pem(pem: &str) -> Option<Vec<u8>> {
+    raw_key(pem, PUBLIC_KEY_LABEL, &ED25519_PUBLIC_KEY_DER)
+  }
+
+  /// SubjectPublicKeyInfo PEM of a raw Ed25519 public key.
+  pub(crate) fn signing_public_key_pem(public_key: &[u8]) -> String {
+    public_key_pem(&ED25519_PUBLIC_KEY_DER, public_key)
+  }
+
+  fn private_key_pem(der_prefix: &[u8], private_key: &[u8]) -> SecretKey {
+    let der = KeyBytes::new([der_prefix, private_key].concat());
+    SecretKey::from(pem(PRIVATE_KEY_LABEL, &der))
+  }
+
+  fn public_key_pem(der_prefix: &[u8], public_key: &[u8]) -> String {
+    pem(PUBLIC_KEY_LABEL, &[der_prefix, public_key].concat())
+  }
+
+  /// PEM the way OpenSSL writes it, base64 in lines of 64 characters. The PEM is allocated at
+  /// its final size and the base64 is wiped, so no copy of a private key is left on the heap.
+  fn pem(label: &str, der: &[u8]) -> String {
+    let encoded = Zeroizing::new(CUSTOM_ENGINE.encode(der));
+    let (begin, end) = (format!("-----BEGIN {}-----\n", label), format!("-----END {}-----\n", label));
+    let mut pem = String::with_capacity(begin.len() + encoded.len() + encoded.len().div_ceil(64) + end.len());
+    pem.push_str(&begin);
+    for line in encoded.as_bytes().chunks(64) {
+      pem.push_str(std::str::from_utf8(line).unwrap_or_default());
+      pem.push('\n');
+    }
+    pem.push_str(&end);
+    pem
+  }
+
+  /// The raw key of a PEM with `label`, whose DER encoding is `der_prefix` followed by 32 key
+  /// bytes. Anything else, including keys of other algorithms, gives `None`.
+  fn raw_key(pem: &str, label: &str, der_prefix: &[u8]) -> Option<Vec<u8>> {
+    let body = pem.trim()
+      .strip_prefix(&format!("-----BEGIN {}-----", label))?
+      .strip_suffix(&format!("-----END {}-----", label))?;
+    // Sized for the whole body, so collecting the base64 never reallocates and leaves copies
+    let mut encoded = Zeroizing::new(String::with_capacity(body.len()));
+    encoded.extend(body.chars().filter(|c| !c.is_ascii_whitespace()));
+    let der = KeyBytes::new(CUSTOM_ENGINE.decode(encoded.as_bytes()).ok()?);
+    let key = der.strip_prefix(der_prefix)?;
+    if key.len() == X25519_KEY_LEN { Some(key.to_vec()) } else { None }
+  }
+
+  /// Writes a file only its owner can read. The mode only applies to new files, so an existing
+  /// file is restricted before anything is written to it.
+  #[cfg(unix)]
+  pub(crate) fn write_private(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+    let mut file = fs::OpenOptions::new().write(true).create(true).truncate(true).mode(0o600).open(path)?;
+    file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    file.write_all(contents)
+  }
+
+  #[cfg(not(unix))]
+  pub(crate) fn write_private(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    fs::write(path, contents)
+  }
@@ -0,0 +1,195 @@
+  use std::collections::BTreeMap;
+  use std::fs;
+  use std::io;
+  use std::path::{Path, PathBuf};
+  use base64::{Engine as _, engine::{self, general_purpose}, alphabet};
+  use zeroize::Zeroizing;
+  use crate::decryption::text::{decrypt_bytes_with, decrypts, DecryptOptions};
+  use crate::encryption::keys::write_private;
+  use crate::encryption::secret::SecretKey;
+  use crate::encryption::text::{encrypt_bytes_with, encrypts, generate_random_bytes, EncryptOptions};
+  use crate::error::{Error, Result};
+  const CUSTOM_ENGINE: engine::GeneralPurpose =
+    engine::GeneralPurpose::new(&alphabet::STANDARD, general_purpose::PAD);
+
+  /// Version of the serialized key list inside the encrypted file.
+  const KEYSTORE_VERSION: u8 = 1;
+  /// Binds the encrypted file to its purpose, so no other cipher under the master passphrase
+  /// opens as a keystore.
+  const KEYSTORE_AAD: &[u8] = b"encrypted_images keystore";
+  /// Length of generated keys before base64 encoding.
+  const GENERATED_KEY_LEN: usize = 32;
+  /// Longest key name in bytes.
+  pub const MAX_NAME_LEN: usize = 255;
+
+  /// Named keys kept in a local file encrypted under a master passphrase.
+  ///
+  /// The file holds a single cipher of the authenticated strength, so it can't be read or
+  /// modified without the master passphrase. Every change is written to the file right away,
+  /// replacing it atomically, and on Unix the file is only readable by its owner. Keys are
+  /// [`SecretKey`]s and are wiped from memory when the keystore is dropped.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::keystore::Keystore;
+  ///
+  /// let path = std::env::temp_dir().join(format!("doc_keystore_{}", std::process::id()));
+  /// let mut keystore = Keystore::create(&path, "master passphrase").unwrap();
+  /// keystore.generate("summer-drop").unwrap();
+  /// let encrypted = keystore.encrypts("summer-drop", "ThisIsJustaTestString", Some("authenticated")).unwrap();
+  ///
+  /// let keystore = Keystore::open(&path, "master passphrase").unwrap();
+  /// assert_eq!(keystore.list(), vec!["summer-drop"]);
+  /// assert_eq!(keystore.decrypts("summer-drop", &encrypted).unwrap(), "ThisIsJustaTestString");
+  /// # std::fs::remove_file(&path).unwrap();
+  /// ```
+  #[derive(Debug)]
+  pub struct Keystore {
+    path: PathBuf,
+    master: SecretKey,
+    keys: BTreeMap<String, SecretKey>,
+  }
+
+  impl Keystore {
+    /// Creates an empty keystore file at `path`. Fails if the file already exists.
+    pub fn create<P: AsRef<Path>>(path: P, master: &str) -> Result<Keystore> {
+      let path = path.as_ref();
+      if path.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, "keystore already exists").into());
+      }
+      let keystore = Keystore { path: path.to_path_buf(), master: SecretKey::from(master), keys: BTreeMap::new() };
+      keystore.save()?;
+      Ok(keystore)
+    }
+
+    /// Opens the keystore file at `path`. Fails with [`Error::MacMismatch`] if `master` is not its
+    /// master passphrase or the file was modified.
+    pub fn open<P: AsRef<Path>>(path: P, master: &str) -> Result<Keystore> {
+      let path = path.as_ref();
+      let encrypted = fs::read_to_string(path)?;
+      let options = DecryptOptions { aad: KEYSTORE_AAD.to_vec(), ..DecryptOptions::default() };
+      let serialized = Zeroizing::new(decrypt_bytes_with(encrypted.trim(), Some(master), &options)?);
+      let keys = deserialize(&serialized).ok_or(Error::InvalidPayload)?;
+      Ok(Keystore { path: path.to_path_buf(), master: SecretKey::from(master), keys })
+    }
+
+    /// Names of all keys in alphabetical order.
+    pub fn list(&self) -> Vec<&str> {
+      self.keys.keys().map(String::as_str).collect()
+    }
+
+    /// The key stored under `name`.
+    pub fn get(&self, name: &str) -> Option<&SecretKey> {
+      self.keys.get(name)
+    }
+
+    /// Generates a random key of 256 bits, stores it under `name` and returns it. The key is
+    /// base64 encoded, so it can be exported and typed like any other passphrase.
+    pub fn generate(&mut self, name: &str) -> Result<&SecretKey> {
+      let random = Zeroizing::new(generate_random_bytes(GENERATED_KEY_LEN));
+      self.import(name, &SecretKey::from(CUSTOM_ENGINE.encode(&random[..])))?;
+      Ok(&self.keys[name])
+    }
+
+    /// Stores an existing passphrase or private key PEM under `name`. Fails with
+    /// [`Error::KeyExists`] if the name is taken and with [`Error::InvalidArgument`] if it is
+    /// empty or longer than [`MAX_NAME_LEN`] bytes.
+    pub fn import(&mut self, name: &str, key: &str) -> Result<()> {
+      if name.is_empty() || name.len() > MAX_NAME_LEN {
+        return Err(Error::InvalidArgument("key name"));
+      }
+      if self.keys.contains_key(name) {
+        return Err(Error::KeyExists);
+      }
+      self.keys.insert(name.to_string(), SecretKey::from(key));
+      self.save().inspect_err(|_| {
+        self.keys.remove(name);
+      })
+    }
+
+    /// A copy of the key stored under `name`, for use outside the keystore.
+    pub fn export(&self, name: &str) -> Option<SecretKey> {
+      self.keys.get(name).cloned()
+    }
+
+    /// Removes the key stored under `name` and returns it. Fails with [`Error::KeyNotFound`] if
+    /// there is none.
+    pub fn delete(&mut self, name: &str) -> Result<SecretKey> {
+      let key = self.keys.remove(name).ok_or(Error::KeyNotFound)?;
+      if let Err(error) = self.save() {
+        self.keys.insert(name.to_string(), key);
+        return Err(error);
+      }
+      Ok(key)
+    }
+
+    /// Encrypts the input text with the key stored under `name`, see
+    /// [`crate::encryption::text::encrypts`]. Fails with [`Error::KeyNotFound`] if there is no
+    /// such key.
+    pub fn encrypts(&self, name: &str, input: &str, strength: Option<&str>) -> Result<String> {
+      encrypts(input, Some(self.get(name).ok_or(Error::KeyNotFound)?), strength)
+    }
+
+    /// Decrypts an encoded result with the key stored under `name`, see
+    /// [`crate::decryption::text::decrypts`]. Fails with [`Error::KeyNotFound`] if there is no
+    /// such key.
+    pub fn decrypts(&self, name: &str, encoded_result: &str) -> Result<String> {
+      decrypts(encoded_result, Some(self.get(name).ok_or(Error::KeyNotFound)?))
+    }
+
+    /// Encrypts all keys under the master passphrase and replaces the file with the result.
+    fn save(&self) -> Result<()> {
+      let options = EncryptOptions { aad: KEYSTORE_AAD.to_vec(), ..EncryptOptions::default() };
+      let encrypted = encrypt_bytes_with(&serialize(&self.keys), Some(&self.master), Some("authenticated"), &options)?;
+      let mut temporary = self.path.clone().into_os_string();
+      temporary.push(".tmp");
+      write_private(Path::new(&temporary), encrypted.as_bytes())?;
+      Ok(fs::rename(&temporary, &self.path)?)
+    }
+  }
+
+  /// Layout: `version[1] || count[4]` followed by `count` entries of `name_len[1] || name ||
+  /// key_len[4] || key`, big endian.
+  ///
+  /// The buffer is allocated at its final size, as growing it would leave unwiped copies of the
+  /// keys behind.
+  fn serialize(keys: &BTreeMap<String, SecretKey>) -> Zeroizing<Vec<u8>> {
+    let len = 5 + keys.iter().map(|(name, key)| 1 + name.len() + 4 + key.len()).sum::<usize>();
+    let mut bytes = Zeroizing::new(Vec::with_capacity(len));
+    bytes.push(KEYSTORE_VERSION);
+    bytes.extend_from_slice(&(keys.len() as u32).to_be_bytes());
+    for (name, key) in keys {
+      bytes.push(name.len() as u8);
+      bytes.extend_from_slice(name.as_bytes());
+      bytes.extend_from_slice(&(key.len() as u32).to_be_bytes());
+      bytes.extend_from_slice(key.as_bytes());
+    }
+    bytes
+  }
+
+  fn deserialize(bytes: &[u8]) -> Option<BTreeMap<String, SecretKey>> {
+    let (&version, rest) = bytes.split_first()?;
+    if version != KEYSTORE_VERSION {
+      return None;
+    }
+    let (count, mut rest) = split_u32(rest)?;
+    let mut keys = BTreeMap::new();
+    for _ in 0..count {
+      let (&name_len, after) = rest.split_first()?;
+      let name = after.get(..name_len as usize)?;
+      let (key_len, after) = split_u32(&after[name.len()..])?;
+      let key = after.get(..key_len as usize)?;
+      keys.insert(String::from_utf8(name.to_vec()).ok()?, SecretKey::from(std::str::from_utf8(key).ok()?));
+      rest = &after[key.len()..];
+    }
+    if !rest.is_empty() {
+      return None;
+    }
+    Some(keys)
+  }
+
+  fn split_u32(bytes: &[u8]) -> Option<(u32, &[u8])> {
+    let value = u32::from_be_bytes(bytes.get(..4)?.try_into().ok()?);
+    Some((value, &bytes[4..]))
+  }
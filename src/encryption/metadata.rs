@@ -0,0 +1,111 @@
+  use std::time::{SystemTime, UNIX_EPOCH};
+  use crate::error::{Error, Result};
+
+  /// Length of the encoded metadata in front of the plaintext.
+  pub(crate) const METADATA_LEN: usize = 25;
+  const HAS_NOT_BEFORE: u8 = 0x01;
+  const HAS_EXPIRES_AT: u8 = 0x02;
+
+  /// Times embedded in the encrypted payload, in seconds since the Unix epoch.
+  ///
+  /// The metadata is encrypted and authenticated together with the plaintext, so it can't be
+  /// read or changed without the key. Decryption fails outside of `not_before..expires_at`, which
+  /// makes an encrypted image usable as a time-limited token. The current time is taken from the
+  /// system clock unless `DecryptOptions::now` supplies one.
+  ///
+  /// Encoded as `fields[1] || created[8] || not_before[8] || expires_at[8]`, big endian, where
+  /// `fields` marks which of the optional times are set and unset times are zero.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::metadata::Metadata;
+  /// use encrypted_images::encryption::text::{encrypts_with, EncryptOptions};
+  /// use encrypted_images::decryption::text::{decrypts_with, DecryptOptions};
+  ///
+  /// let key = Some("your_secret_key");
+  /// let metadata = Metadata { created: 1_700_000_000, not_before: None, expires_at: Some(1_700_086_400) };
+  /// let options = EncryptOptions { metadata: Some(metadata), ..EncryptOptions::default() };
+  /// let ticket = encrypts_with("Entry to hall B", key, Some("authenticated"), &options).unwrap();
+  ///
+  /// let during_event = DecryptOptions { now: Some(1_700_050_000), ..DecryptOptions::default() };
+  /// let after_event = DecryptOptions { now: Some(1_700_090_000), ..DecryptOptions::default() };
+  /// assert_eq!(decrypts_with(&ticket, key, &during_event).unwrap(), "Entry to hall B");
+  /// assert!(decrypts_with(&ticket, key, &after_event).is_err());
+  /// ```
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub struct Metadata {
+    /// When the plaintext was encrypted.
+    pub created: u64,
+    /// Decryption fails before this time.
+    pub not_before: Option<u64>,
+    /// Decryption fails at and after this time.
+    pub expires_at: Option<u64>,
+  }
+
+  impl Metadata {
+    /// Metadata created at the current system time, without a validity window.
+    pub fn now() -> Metadata {
+      Metadata { created: unix_time(), not_before: None, expires_at: None }
+    }
+
+    /// Whether `time` lies within `not_before..expires_at`.
+    pub fn is_valid_at(&self, time: u64) -> bool {
+      self.check(time).is_ok()
+    }
+
+    /// Fails with [`Error::NotYetValid`] or [`Error::Expired`] if `time` lies outside of
+    /// `not_before..expires_at`.
+    pub(crate) fn check(&self, time: u64) -> Result<()> {
+      if self.not_before.is_some_and(|not_before| time < not_before) {
+        return Err(Error::NotYetValid);
+      }
+      if self.expires_at.is_some_and(|expires_at| time >= expires_at) {
+        return Err(Error::Expired);
+      }
+      Ok(())
+    }
+
+    pub(crate) fn to_bytes(self) -> Vec<u8> {
+      let mut fields = 0;
+      if self.not_before.is_some() {
+        fields |= HAS_NOT_BEFORE;
+      }
+      if self.expires_at.is_some() {
+        fields |= HAS_EXPIRES_AT;
+      }
+      let mut bytes = vec![fields];
+      bytes.extend_from_slice(&self.created.to_be_bytes());
+      bytes.extend_from_slice(&self.not_before.unwrap_or(0).to_be_bytes());
+      bytes.extend_from_slice(&self.expires_at.unwrap_or(0).to_be_bytes());
+      bytes
+    }
+
+    /// Splits the metadata off the front of a decrypted payload.
+    pub(crate) fn parse(bytes: &[u8]) -> Result<(Metadata, &[u8])> {
+      if bytes.len() < METADATA_LEN {
+        return Err(Error::InvalidPayload);
+      }
+      let (metadata, rest) = bytes.split_at(METADATA_LEN);
+      let fields = metadata[0];
+      if fields & !(HAS_NOT_BEFORE | HAS_EXPIRES_AT) != 0 {
+        return Err(Error::InvalidPayload);
+      }
+      let time = |at: usize| {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&metadata[at..at + 8]);
+        u64::from_be_bytes(bytes)
+      };
+      let metadata = Metadata {
+        created: time(1),
+        not_before: if fields & HAS_NOT_BEFORE != 0 { Some(time(9)) } else { None },
+        expires_at: if fields & HAS_EXPIRES_AT != 0 { Some(time(17)) } else { None },
+      };
+      Ok((metadata, rest))
+    }
+  }
+
+  /// Seconds since the Unix epoch according to the system clock, zero if it is set before 1970.
+  pub(crate) fn unix_time() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+  }
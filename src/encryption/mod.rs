@@ -1,2 +1,18 @@
 pub mod text;
 pub mod images;
+pub mod kdf;
+pub mod envelope;
+pub mod keys;
+pub mod recipients;
+pub mod signing;
+pub mod rekey;
+pub mod shares;
+pub mod compression;
+pub mod padding;
+pub mod deniable;
+pub mod policy;
+pub mod metadata;
+pub mod secret;
+pub mod keystore;
+pub(crate) mod chunks;
+pub mod stream;
@@ -0,0 +1,90 @@
+  use crate::encryption::text::generate_random_bytes;
+  use crate::error::{Error, Result};
+
+  /// Padding of the plaintext before it is encrypted, so the length of a ciphertext and the size
+  /// of its image don't reveal the length of the input.
+  ///
+  /// The plaintext is preceded by its length as 4 bytes big endian and followed by as many random
+  /// bytes as the policy needs, or zero bytes for the deterministic default strength so equal
+  /// inputs keep giving equal ciphers. The envelope header records that the plaintext was padded
+  /// and `decrypts` strips the padding again. Compressed plaintext is padded after compression.
+  /// All sizes count the plaintext including its length, so inputs of the same padded size give
+  /// ciphers and images of the same size.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::padding::Padding;
+  /// use encrypted_images::encryption::text::{encrypts_with, EncryptOptions};
+  /// use encrypted_images::decryption::text::decrypts;
+  ///
+  /// let key = Some("your_secret_key");
+  /// let options = EncryptOptions { padding: Padding::Bucket(64), ..EncryptOptions::default() };
+  /// let short = encrypts_with("Yes", key, Some("authenticated"), &options).unwrap();
+  /// let long = encrypts_with("ThisIsJustaTestString", key, Some("authenticated"), &options).unwrap();
+  ///
+  /// assert_eq!(short.len(), long.len());
+  /// assert_eq!(decrypts(&short, key).unwrap(), "Yes");
+  /// ```
+  #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+  pub enum Padding {
+    /// Encrypt the plaintext without padding.
+    #[default]
+    None,
+    /// Pad to the next multiple of this many bytes. Fails with [`Error::InvalidArgument`] for 0.
+    Bucket(usize),
+    /// Pad to the next power of two, which hides the length up to a factor of two with at most
+    /// doubling the size.
+    PowerOfTwo,
+    /// Pad to exactly this many bytes. Fails with [`Error::InvalidArgument`] if the plaintext
+    /// doesn't fit.
+    Fixed(usize),
+  }
+
+  /// Length of the plaintext length in front of padded plaintext.
+  pub(crate) const LENGTH_LEN: usize = 4;
+
+  impl Padding {
+    /// Length of `len` plaintext bytes once padded, `None` without padding.
+    pub(crate) fn padded_len(self, len: usize) -> Result<Option<usize>> {
+      self.padded_size(len.checked_add(LENGTH_LEN).ok_or(Error::InvalidArgument("padding"))?)
+    }
+
+    /// Size the policy pads `minimum` bytes to, `None` without padding.
+    pub(crate) fn padded_size(self, minimum: usize) -> Result<Option<usize>> {
+      let padded_len = match self {
+        Padding::None => return Ok(None),
+        Padding::Bucket(0) => return Err(Error::InvalidArgument("padding bucket size")),
+        Padding::Bucket(size) => minimum.div_ceil(size).checked_mul(size),
+        Padding::PowerOfTwo => minimum.checked_next_power_of_two(),
+        Padding::Fixed(size) if size < minimum => return Err(Error::InvalidArgument("input is longer than the fixed padding size")),
+        Padding::Fixed(size) => Some(size),
+      };
+      padded_len.map(Some).ok_or(Error::InvalidArgument("padding"))
+    }
+  }
+
+  /// Pads `input` according to `padding`, returns `None` if it asks for no padding. The filler
+  /// is random unless `deterministic` is set.
+  pub(crate) fn pad(input: &[u8], padding: Padding, deterministic: bool) -> Result<Option<Vec<u8>>> {
+    let Some(padded_len) = padding.padded_len(input.len())? else { return Ok(None) };
+    pad_to(input, padded_len, deterministic).map(Some)
+  }
+
+  /// Pads `input` to exactly `padded_len` bytes like `pad`.
+  pub(crate) fn pad_to(input: &[u8], padded_len: usize, deterministic: bool) -> Result<Vec<u8>> {
+    let len = u32::try_from(input.len()).map_err(|_| Error::InvalidArgument("input is too long to pad"))?;
+    let filler_len = padded_len.checked_sub(LENGTH_LEN + input.len()).ok_or(Error::InvalidArgument("input is longer than the padding size"))?;
+    let filler = if deterministic { vec![0; filler_len] } else { generate_random_bytes(filler_len) };
+    Ok([&len.to_be_bytes()[..], input, &filler].concat())
+  }
+
+  /// Strips the padding added by `pad`, fails if there is none.
+  pub(crate) fn unpad(input: &[u8]) -> Result<&[u8]> {
+    if input.len() < LENGTH_LEN {
+      return Err(Error::InvalidPayload);
+    }
+    let (len, rest) = input.split_at(LENGTH_LEN);
+    let len = u32::from_be_bytes(len.try_into().map_err(|_| Error::InvalidPayload)?) as usize;
+    rest.get(..len).ok_or(Error::InvalidPayload)
+  }
@@ -0,0 +1,201 @@
+  use std::sync::{PoisonError, RwLock};
+  use crate::encryption::envelope::{Header, KeyBlock, Slot, Suite};
+  use crate::encryption::kdf::KdfParams;
+  use crate::encryption::text::DEFAULT_KEY;
+  use crate::error::{Error, Result};
+
+  static POLICY: RwLock<Option<SecurityPolicy>> = RwLock::new(None);
+
+  /// Rules every encryption and decryption of the process has to follow once installed.
+  ///
+  /// Without a policy everything the crate supports is allowed. [`SecurityPolicy::install`]
+  /// makes a policy apply to all functions that encrypt or decrypt text, bytes and streams,
+  /// also through rekeying, recipients, deniable results and shares, which then fail with
+  /// [`Error::PolicyViolation`] naming the broken rule instead of encrypting or decrypting. The
+  /// default policy allows everything, so a policy only needs to set the rules it wants, and
+  /// [`SecurityPolicy::strict`] sets all of them.
+  ///
+  /// The passphrase entropy only applies to new passphrases, when encrypting. The other rules
+  /// also apply to decryption, so a cipher made with weaker settings is refused as well. Results
+  /// in the 1.x layout count as [`Suite::Aes128CbcHmacSha256`] without a key derivation.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::Error;
+  /// use encrypted_images::encryption::policy::SecurityPolicy;
+  /// use encrypted_images::encryption::text::encrypts;
+  ///
+  /// SecurityPolicy::strict().install();
+  ///
+  /// assert!(matches!(encrypts("ThisIsJustaTestString", None, Some("authenticated")), Err(Error::PolicyViolation(_))));
+  /// assert!(matches!(encrypts("ThisIsJustaTestString", Some("correct horse battery staple"), None), Err(Error::PolicyViolation(_))));
+  /// assert!(encrypts("ThisIsJustaTestString", Some("correct horse battery staple"), Some("authenticated")).is_ok());
+  /// ```
+  #[derive(Clone, Debug, Default, PartialEq, Eq)]
+  pub struct SecurityPolicy {
+    /// Refuse the built-in default key, which is used when no key is given.
+    pub forbid_default_key: bool,
+    /// Refuse the deterministic default strength, AES-256-GCM-SIV.
+    pub forbid_deterministic: bool,
+    /// Smallest estimated entropy of a new passphrase in bits, see [`estimate_entropy`]. 0
+    /// allows any passphrase.
+    pub min_passphrase_entropy: u32,
+    /// Smallest scrypt cost. Every parameter of a passphrase key derivation has to be at least
+    /// the one given here.
+    pub min_kdf: Option<KdfParams>,
+    /// The cipher suites allowed, all of them if `None`.
+    pub allowed_suites: Option<Vec<Suite>>,
+  }
+
+  impl SecurityPolicy {
+    /// A policy with every rule set: no default key, no deterministic encryption, passphrases of
+    /// at least 60 bits, the default scrypt cost and only the AES-256-GCM suites.
+    pub fn strict() -> SecurityPolicy {
+      SecurityPolicy {
+        forbid_default_key: true,
+        forbid_deterministic: true,
+        min_passphrase_entropy: 60,
+        min_kdf: Some(KdfParams::default()),
+        allowed_suites: Some(vec![Suite::Aes256Gcm, Suite::Aes256GcmStream]),
+      }
+    }
+
+    /// Makes this the policy of the whole process, replacing an installed one.
+    ///
+    /// # Examples
+    ///
+    /// Results encrypted before are refused once the policy forbids their settings:
+    ///
+    /// ```
+    /// use std::io::Write;
+    /// use encrypted_images::Error;
+    /// use encrypted_images::encryption::policy::SecurityPolicy;
+    /// use encrypted_images::encryption::stream::StreamEncryptor;
+    /// use encrypted_images::encryption::text::encrypts;
+    /// use encrypted_images::decryption::stream::StreamDecryptor;
+    /// use encrypted_images::decryption::text::decrypts;
+    ///
+    /// let deterministic = encrypts("ThisIsJustaTestString", Some("correct horse battery staple"), None).unwrap();
+    /// let mut encryptor = StreamEncryptor::new(Vec::new(), None).unwrap();
+    /// encryptor.write_all(b"ThisIsJustaTestString").unwrap();
+    /// let stream = encryptor.finish().unwrap();
+    ///
+    /// SecurityPolicy::strict().install();
+    /// assert!(matches!(decrypts(&deterministic, Some("correct horse battery staple")), Err(Error::PolicyViolation(_))));
+    /// let mut decryptor = StreamDecryptor::new(Vec::new(), None);
+    /// let error = decryptor.write_all(&stream).unwrap_err();
+    /// assert!(matches!(*error.into_inner().unwrap().downcast::<Error>().unwrap(), Error::PolicyViolation(_)));
+    ///
+    /// SecurityPolicy::uninstall();
+    /// assert_eq!(decrypts(&deterministic, Some("correct horse battery staple")).unwrap(), "ThisIsJustaTestString");
+    /// ```
+    pub fn install(self) {
+      *POLICY.write().unwrap_or_else(PoisonError::into_inner) = Some(self);
+    }
+
+    /// Removes the installed policy and returns it, allowing everything again.
+    pub fn uninstall() -> Option<SecurityPolicy> {
+      POLICY.write().unwrap_or_else(PoisonError::into_inner).take()
+    }
+
+    /// The installed policy, if any.
+    pub fn current() -> Option<SecurityPolicy> {
+      POLICY.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Checks a new passphrase against the policy, for example before it is stored or shown as
+    /// accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use encrypted_images::encryption::policy::SecurityPolicy;
+    ///
+    /// let policy = SecurityPolicy::strict();
+    /// assert!(policy.check_passphrase("hunter2").is_err());
+    /// assert!(policy.check_passphrase("correct horse battery staple").is_ok());
+    /// ```
+    pub fn check_passphrase(&self, passphrase: &str) -> Result<()> {
+      self.check_key(passphrase)?;
+      if estimate_entropy(passphrase) < self.min_passphrase_entropy as f64 {
+        return Err(Error::PolicyViolation("passphrase is too weak"));
+      }
+      Ok(())
+    }
+
+    fn check_key(&self, key: &str) -> Result<()> {
+      if self.forbid_default_key && key == DEFAULT_KEY {
+        return Err(Error::PolicyViolation("default key"));
+      }
+      Ok(())
+    }
+
+    /// Checks the suite and the key derivation of an envelope, or of the 1.x layout for `None`.
+    pub(crate) fn check_header(&self, header: Option<&Header>) -> Result<()> {
+      let suite = header.map_or(Suite::Aes128CbcHmacSha256, |header| header.suite);
+      if self.allowed_suites.as_ref().is_some_and(|suites| !suites.contains(&suite)) {
+        return Err(Error::PolicyViolation("cipher suite"));
+      }
+      if self.forbid_deterministic && suite == Suite::Aes256GcmSiv {
+        return Err(Error::PolicyViolation("deterministic encryption"));
+      }
+      let Some(min_kdf) = self.min_kdf else { return Ok(()) };
+      let kdfs: Vec<&KdfParams> = match header.map(|header| &header.key) {
+        None => return Err(Error::PolicyViolation("the 1.x layout has no key derivation")),
+        Some(KeyBlock::Passphrase { kdf, .. }) => vec![kdf],
+        Some(KeyBlock::Recipients(slots)) => slots.iter().filter_map(|slot| match slot {
+          Slot::Passphrase { kdf, .. } => Some(kdf),
+          Slot::X25519 { .. } => None,
+        }).collect(),
+      };
+      if kdfs.iter().any(|kdf| kdf.log_n < min_kdf.log_n || kdf.r < min_kdf.r || kdf.p < min_kdf.p) {
+        return Err(Error::PolicyViolation("key derivation cost"));
+      }
+      Ok(())
+    }
+  }
+
+  /// Estimates the entropy of a passphrase in bits, as its length in characters times the base 2
+  /// logarithm of the size of the character classes it uses: lowercase and uppercase letters,
+  /// digits, ASCII symbols and non-ASCII characters. Passphrases made of words or patterns are
+  /// overestimated, so treat the result as an upper bound.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::policy::estimate_entropy;
+  ///
+  /// assert_eq!(estimate_entropy(""), 0.0);
+  /// assert!(estimate_entropy("welovenfts") < estimate_entropy("We love NFTs 4ever!"));
+  /// ```
+  pub fn estimate_entropy(passphrase: &str) -> f64 {
+    let uses = |class: fn(&char) -> bool| passphrase.chars().any(|c| class(&c));
+    let mut pool = 0u32;
+    if uses(char::is_ascii_lowercase) { pool += 26; }
+    if uses(char::is_ascii_uppercase) { pool += 26; }
+    if uses(char::is_ascii_digit) { pool += 10; }
+    if uses(|c| c.is_ascii() && !c.is_ascii_alphanumeric()) { pool += 33; }
+    if uses(|c| !c.is_ascii()) { pool += 100; }
+    if pool == 0 {
+      return 0.0;
+    }
+    passphrase.chars().count() as f64 * (pool as f64).log2()
+  }
+
+  /// Checks an envelope about to be written and its new passphrases against the installed policy.
+  pub(crate) fn check_encryption(header: &Header, passphrases: &[&str]) -> Result<()> {
+    let policy = POLICY.read().unwrap_or_else(PoisonError::into_inner);
+    let Some(policy) = policy.as_ref() else { return Ok(()) };
+    policy.check_header(Some(header))?;
+    passphrases.iter().try_for_each(|passphrase| policy.check_passphrase(passphrase))
+  }
+
+  /// Checks a cipher about to be decrypted and its key against the installed policy, `header`
+  /// is `None` for the 1.x layout.
+  pub(crate) fn check_decryption(header: Option<&Header>, key: &str) -> Result<()> {
+    let policy = POLICY.read().unwrap_or_else(PoisonError::into_inner);
+    let Some(policy) = policy.as_ref() else { return Ok(()) };
+    policy.check_header(header)?;
+    policy.check_key(key)
+  }
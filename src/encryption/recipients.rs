@@ -0,0 +1,274 @@
+  use base64::{Engine as _, engine::{self, general_purpose}, alphabet};
+  use crate::decryption::text::open_body;
+  use crate::encryption::envelope::{Header, KeyBlock, Slot, Suite};
+  use crate::encryption::kdf::{derive_key, expand_key, KdfParams, SALT_LEN};
+  use crate::encryption::keys::{private_key_from_pem, public_key_from_pem, unwrap, unwrap_with_private_key, wrap, wrap_for_public_key, CONTENT_KEY_LEN};
+  use crate::encryption::policy::{check_decryption, check_encryption};
+  use crate::encryption::secret::KeyBytes;
+  use crate::encryption::text::{associated_data, generate_random_bytes, master_key, seal, SuiteKeys};
+  use crate::error::{Error, Result};
+  const CUSTOM_ENGINE: engine::GeneralPurpose =
+    engine::GeneralPurpose::new(&alphabet::STANDARD, general_purpose::PAD);
+
+  /// Most recipients one envelope can hold.
+  pub const MAX_RECIPIENTS: usize = 255;
+  /// Most passphrase recipients one envelope can hold. Decrypting may run scrypt once for each of
+  /// them, so envelopes with more are refused.
+  pub const MAX_PASSPHRASE_RECIPIENTS: usize = 8;
+  const PASSPHRASE_WRAP_KEY_INFO: &[u8] = b"encrypted_images v2 passphrase wrap key";
+
+  /// Someone who can open a multi-recipient envelope.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  pub enum Recipient<'a> {
+    /// Opens the envelope with this passphrase.
+    Passphrase(&'a str),
+    /// Opens the envelope with the private key belonging to this X25519 public key PEM.
+    PublicKey(&'a str),
+  }
+
+  /// Encrypts the input text once for several recipients.
+  ///
+  /// A random content key encrypts the input with AES-256-GCM and is then wrapped once per
+  /// recipient, so every recipient opens the result with their own passphrase or private key PEM
+  /// through `decrypts`. Fails with [`Error::InvalidArgument`] without recipients, with more than
+  /// [`MAX_RECIPIENTS`] or with more than [`MAX_PASSPHRASE_RECIPIENTS`] passphrases, and with
+  /// [`Error::InvalidKey`] if a public key is not an X25519 public key PEM.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::keys::KeyPair;
+  /// use encrypted_images::encryption::recipients::{encrypts_for, Recipient};
+  /// use encrypted_images::decryption::text::decrypts;
+  ///
+  /// let collector = KeyPair::generate().unwrap();
+  /// let public_key = collector.public_key_pem().unwrap();
+  /// let recipients = [Recipient::Passphrase("artist passphrase"), Recipient::PublicKey(&public_key)];
+  /// let encrypted = encrypts_for("ThisIsJustaTestString", &recipients).unwrap();
+  ///
+  /// assert_eq!(decrypts(&encrypted, Some("artist passphrase")).unwrap(), "ThisIsJustaTestString");
+  /// assert_eq!(decrypts(&encrypted, Some(&collector.private_key_pem().unwrap())).unwrap(), "ThisIsJustaTestString");
+  /// ```
+  pub fn encrypts_for(input: &str, recipients: &[Recipient]) -> Result<String> {
+    encrypt_bytes_for(input.as_bytes(), recipients)
+  }
+
+  /// Encrypts the input text for several recipients like [`encrypts_for`], bound to associated
+  /// data, see `encryption::text::EncryptOptions::aad`. Every recipient decrypts it with
+  /// `decrypts_with` and the same associated data.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::recipients::{encrypts_for_with, Recipient};
+  /// use encrypted_images::decryption::text::{decrypts, decrypts_with, DecryptOptions};
+  ///
+  /// let recipients = [Recipient::Passphrase("first passphrase"), Recipient::Passphrase("second passphrase")];
+  /// let encrypted = encrypts_for_with("ThisIsJustaTestString", &recipients, b"token 42").unwrap();
+  ///
+  /// let token_42 = DecryptOptions { aad: b"token 42".to_vec(), ..DecryptOptions::default() };
+  /// assert_eq!(decrypts_with(&encrypted, Some("second passphrase"), &token_42).unwrap(), "ThisIsJustaTestString");
+  /// assert!(decrypts(&encrypted, Some("second passphrase")).is_err());
+  /// ```
+  pub fn encrypts_for_with(input: &str, recipients: &[Recipient], aad: &[u8]) -> Result<String> {
+    encrypt_bytes_for_with(input.as_bytes(), recipients, aad)
+  }
+
+  /// Encrypts arbitrary bytes once for several recipients, like [`encrypts_for`].
+  pub fn encrypt_bytes_for(input: &[u8], recipients: &[Recipient]) -> Result<String> {
+    encrypt_bytes_for_with(input, recipients, &[])
+  }
+
+  /// Encrypts arbitrary bytes once for several recipients, bound to associated data like
+  /// [`encrypts_for_with`].
+  pub fn encrypt_bytes_for_with(input: &[u8], recipients: &[Recipient], aad: &[u8]) -> Result<String> {
+    if recipients.is_empty() || recipients.len() > MAX_RECIPIENTS {
+      return Err(Error::InvalidArgument("number of recipients"));
+    }
+    if new_passphrases(recipients).len() > MAX_PASSPHRASE_RECIPIENTS {
+      return Err(Error::InvalidArgument("number of passphrase recipients"));
+    }
+    let content_key = KeyBytes::new(generate_random_bytes(CONTENT_KEY_LEN));
+    let slots = recipients.iter().map(|recipient| wrap_for(*recipient, &content_key)).collect::<Result<Vec<Slot>>>()?;
+    let header = Header::new(Suite::Aes256Gcm, KeyBlock::Recipients(slots));
+    check_encryption(&header, &new_passphrases(recipients))?;
+    seal(&header, &SuiteKeys::expand(&content_key, header.suite)?, input, aad)
+  }
+
+  /// Gives one more recipient access to an encrypted result.
+  ///
+  /// `credential` is the passphrase or private key PEM of anyone who can already decrypt
+  /// `encoded_result`. The content key is wrapped for `recipient` and the body is encrypted again
+  /// under a fresh nonce, because the list of recipients is authenticated with it. Results of
+  /// `encrypts` with a single passphrase are converted to the multi-recipient format under a new
+  /// random content key, so `recipient` learns nothing that opens other results of the same
+  /// passphrase. Fails if `credential` doesn't decrypt `encoded_result`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::text::encrypts;
+  /// use encrypted_images::encryption::recipients::{add_recipient, Recipient};
+  /// use encrypted_images::decryption::text::decrypts;
+  ///
+  /// let encrypted = encrypts("ThisIsJustaTestString", Some("first passphrase"), Some("authenticated")).unwrap();
+  /// let shared = add_recipient(&encrypted, "first passphrase", Recipient::Passphrase("second passphrase")).unwrap();
+  ///
+  /// assert_eq!(decrypts(&shared, Some("first passphrase")).unwrap(), "ThisIsJustaTestString");
+  /// assert_eq!(decrypts(&shared, Some("second passphrase")).unwrap(), "ThisIsJustaTestString");
+  /// ```
+  pub fn add_recipient(encoded_result: &str, credential: &str, recipient: Recipient) -> Result<String> {
+    add_recipient_with(encoded_result, credential, recipient, &[])
+  }
+
+  /// Gives one more recipient access like [`add_recipient`], to a result encrypted with
+  /// associated data. `aad` has to be the associated data it was encrypted with and stays bound
+  /// to the new result.
+  pub fn add_recipient_with(encoded_result: &str, credential: &str, recipient: Recipient, aad: &[u8]) -> Result<String> {
+    let (header, content_key, plaintext) = open(encoded_result, credential, aad)?;
+    let (content_key, mut slots) = match header.key {
+      KeyBlock::Passphrase { .. } => {
+        // The passphrase key may open other results with the same passphrase, so it isn't shared
+        let content_key = KeyBytes::new(generate_random_bytes(CONTENT_KEY_LEN));
+        let slots = vec![wrap_for(Recipient::Passphrase(credential), &content_key)?];
+        (content_key, slots)
+      }
+      KeyBlock::Recipients(slots) => (content_key, slots),
+    };
+    if slots.len() >= MAX_RECIPIENTS {
+      return Err(Error::InvalidArgument("number of recipients"));
+    }
+    slots.push(wrap_for(recipient, &content_key)?);
+    check_passphrase_slots(&slots)?;
+    let header = Header { key: KeyBlock::Recipients(slots), ..header };
+    check_encryption(&header, &new_passphrases(&[recipient]))?;
+    seal(&header, &SuiteKeys::expand(&content_key, header.suite)?, &plaintext, aad)
+  }
+
+  /// Takes a recipient's slot out of an encrypted result.
+  ///
+  /// `credential` is the passphrase or private key PEM of anyone who can decrypt
+  /// `encoded_result`. Fails if it can't, if `recipient` has no slot or if it holds the only
+  /// slot.
+  ///
+  /// The content key itself is unchanged, so copies of the result made before the removal still
+  /// open with the removed credential. To revoke access completely, encrypt the plaintext again
+  /// with [`encrypts_for`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::recipients::{encrypts_for, remove_recipient, Recipient};
+  /// use encrypted_images::decryption::text::decrypts;
+  ///
+  /// let recipients = [Recipient::Passphrase("first passphrase"), Recipient::Passphrase("second passphrase")];
+  /// let encrypted = encrypts_for("ThisIsJustaTestString", &recipients).unwrap();
+  /// let removed = remove_recipient(&encrypted, "first passphrase", Recipient::Passphrase("second passphrase")).unwrap();
+  ///
+  /// assert!(decrypts(&removed, Some("second passphrase")).is_err());
+  /// ```
+  pub fn remove_recipient(encoded_result: &str, credential: &str, recipient: Recipient) -> Result<String> {
+    remove_recipient_with(encoded_result, credential, recipient, &[])
+  }
+
+  /// Takes a recipient's slot out like [`remove_recipient`], of a result encrypted with
+  /// associated data. `aad` has to be the associated data it was encrypted with and stays bound
+  /// to the new result.
+  pub fn remove_recipient_with(encoded_result: &str, credential: &str, recipient: Recipient, aad: &[u8]) -> Result<String> {
+    let (header, content_key, plaintext) = open(encoded_result, credential, aad)?;
+    let mut slots = match header.key {
+      KeyBlock::Recipients(slots) => slots,
+      KeyBlock::Passphrase { .. } => return Err(Error::UnsupportedFormat),
+    };
+    let position = slots.iter().position(|slot| holds(slot, recipient)).ok_or(Error::InvalidArgument("recipient has no slot"))?;
+    slots.remove(position);
+    if slots.is_empty() {
+      return Err(Error::InvalidArgument("can't remove the only recipient"));
+    }
+    let header = Header { key: KeyBlock::Recipients(slots), ..header };
+    check_encryption(&header, &[])?;
+    seal(&header, &SuiteKeys::expand(&content_key, header.suite)?, &plaintext, aad)
+  }
+
+  /// Fails with [`Error::InvalidArgument`] if `slots` hold more than
+  /// [`MAX_PASSPHRASE_RECIPIENTS`] passphrase slots.
+  pub(crate) fn check_passphrase_slots(slots: &[Slot]) -> Result<()> {
+    if slots.iter().filter(|slot| matches!(slot, Slot::Passphrase { .. })).count() > MAX_PASSPHRASE_RECIPIENTS {
+      return Err(Error::InvalidArgument("number of passphrase recipients"));
+    }
+    Ok(())
+  }
+
+  /// The passphrases among `recipients`, which the security policy checks like any new
+  /// passphrase.
+  pub(crate) fn new_passphrases<'a>(recipients: &[Recipient<'a>]) -> Vec<&'a str> {
+    recipients.iter().filter_map(|recipient| match recipient {
+      Recipient::Passphrase(passphrase) => Some(*passphrase),
+      Recipient::PublicKey(_) => None,
+    }).collect()
+  }
+
+  /// Decrypts `encoded_result` and returns its header, content key and plaintext. The plaintext is
+  /// returned as it was encrypted, still compressed if the header says so. `aad` is the
+  /// associated data it was encrypted with.
+  pub(crate) fn open(encoded_result: &str, credential: &str, aad: &[u8]) -> Result<(Header, KeyBytes, Vec<u8>)> {
+    let bytes = CUSTOM_ENGINE.decode(encoded_result).map_err(|_| Error::InvalidBase64)?;
+    let (header, header_len) = Header::parse(&bytes)?;
+    check_decryption(Some(&header), credential)?;
+    let content_key = master_key(credential, &header)?;
+    let (header_bytes, body) = bytes.split_at(header_len);
+    let plaintext = open_body(header.suite, &SuiteKeys::expand(&content_key, header.suite)?, &associated_data(header_bytes, aad), body)?;
+    Ok((header, content_key, plaintext))
+  }
+
+  pub(crate) fn wrap_for(recipient: Recipient, content_key: &[u8]) -> Result<Slot> {
+    match recipient {
+      Recipient::Passphrase(passphrase) => {
+        let kdf = KdfParams::default();
+        let salt = generate_random_bytes(SALT_LEN);
+        let wrapped_key = wrap(&passphrase_wrap_key(passphrase, &salt, &kdf)?, content_key)?;
+        Ok(Slot::Passphrase { kdf, salt, wrapped_key })
+      }
+      Recipient::PublicKey(public_key) => wrap_for_public_key(public_key, content_key),
+    }
+  }
+
+  /// Whether `slot` belongs to `recipient`.
+  fn holds(slot: &Slot, recipient: Recipient) -> bool {
+    match (slot, recipient) {
+      (Slot::Passphrase { .. }, Recipient::Passphrase(passphrase)) => unwrap_slots(passphrase, std::slice::from_ref(slot)).is_ok(),
+      (Slot::X25519 { recipient_public_key, .. }, Recipient::PublicKey(public_key)) => {
+        public_key_from_pem(public_key).as_ref() == Some(recipient_public_key)
+      }
+      _ => false,
+    }
+  }
+
+  /// Tries every slot `credential` could open and returns the first content key it unwraps.
+  /// Fails with [`Error::WrongKey`] if there is none.
+  pub(crate) fn unwrap_slots(credential: &str, slots: &[Slot]) -> Result<KeyBytes> {
+    let private_key = private_key_from_pem(credential);
+    slots.iter().find_map(|slot| unwrap_slot(credential, private_key.as_ref(), slot).ok()).ok_or(Error::WrongKey)
+  }
+
+  /// Position of the first slot `credential` opens.
+  pub(crate) fn opened_slot(credential: &str, slots: &[Slot]) -> Option<usize> {
+    let private_key = private_key_from_pem(credential);
+    slots.iter().position(|slot| unwrap_slot(credential, private_key.as_ref(), slot).is_ok())
+  }
+
+  /// A private key PEM is only tried on public key slots, anything else only on passphrase slots.
+  fn unwrap_slot(credential: &str, private_key: Option<&KeyBytes>, slot: &Slot) -> Result<KeyBytes> {
+    match (slot, private_key) {
+      (Slot::Passphrase { kdf, salt, wrapped_key }, None) => unwrap(&passphrase_wrap_key(credential, salt, kdf)?, wrapped_key),
+      (Slot::X25519 { recipient_public_key, ephemeral_public_key, wrapped_key }, Some(private_key)) => {
+        unwrap_with_private_key(private_key, recipient_public_key, ephemeral_public_key, wrapped_key)
+      }
+      _ => Err(Error::WrongKey),
+    }
+  }
+
+  fn passphrase_wrap_key(passphrase: &str, salt: &[u8], kdf: &KdfParams) -> Result<KeyBytes> {
+    let stretched = derive_key(passphrase, salt, kdf, CONTENT_KEY_LEN)?;
+    expand_key(&stretched, PASSPHRASE_WRAP_KEY_INFO, CONTENT_KEY_LEN)
+  }
@@ -0,0 +1,117 @@
+  use crate::decryption::images::decode_image_and_extract_text;
+  use crate::decryption::text::{decrypt_bytes_with, DecryptOptions};
+  use crate::encryption::envelope::{is_envelope, Header, KeyBlock, Slot, Suite};
+  use crate::encryption::images::ImageSettings;
+  use crate::encryption::kdf::SALT_LEN;
+  use crate::encryption::keys::{public_key_from_pem, wrap_for_x25519, CONTENT_KEY_LEN};
+  use crate::encryption::policy::check_encryption;
+  use crate::encryption::recipients::{new_passphrases, open, opened_slot, wrap_for, Recipient};
+  use crate::encryption::secret::KeyBytes;
+  use crate::encryption::text::{encrypt_bytes, generate_random_bytes, seal, SuiteKeys, CBC_IV_LEN, DEFAULT_KEY, DETERMINISTIC_SALT};
+  use crate::error::{Error, Result};
+  use base64::{Engine as _, engine::{self, general_purpose}, alphabet};
+  const CUSTOM_ENGINE: engine::GeneralPurpose =
+    engine::GeneralPurpose::new(&alphabet::STANDARD, general_purpose::PAD);
+
+  const PNG_SIGNATURE: &[u8] = b"\x89PNG";
+
+  /// Re-encrypts an encrypted text or an encoded image under a new key.
+  ///
+  /// The plaintext is only ever held inside this function. `encrypted` is either a result of the
+  /// `encrypts` family or an image from `create_img`. Images are drawn again with their original
+  /// style, watermark and gradient, see [`ImageSettings::from_img`]. An image signature can't be
+  /// carried over, sign the new image again with `sign_img`.
+  ///
+  /// For results of `encrypts` the strength and scrypt cost are kept and `new_key` is the new
+  /// passphrase. 1.x results are upgraded to the current format, with the default strength if
+  /// they were made with it and the advanced strength otherwise. Multi-recipient results get a
+  /// new content key, so neither `old_key` nor an earlier copy of the result opens the new one.
+  /// The slot that `old_key` opens is replaced by one for `new_key`, a passphrase or an X25519
+  /// public key PEM, and the other public key recipients keep their access.
+  ///
+  /// Fails if `old_key` doesn't decrypt `encrypted` or the settings of an image can't be
+  /// recovered. Fails with [`Error::InvalidArgument`] if a multi-recipient result has passphrase
+  /// recipients besides `old_key`, as the new content key can't be wrapped for them without their
+  /// passphrases; take them out with `remove_recipient` first and add them again afterwards.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::text::encrypts;
+  /// use encrypted_images::encryption::rekey::rekey;
+  /// use encrypted_images::decryption::text::decrypts;
+  ///
+  /// let encrypted = encrypts("ThisIsJustaTestString", Some("leaked passphrase"), Some("authenticated")).unwrap();
+  /// let rekeyed = rekey(&encrypted, Some("leaked passphrase"), Some("new passphrase")).unwrap();
+  ///
+  /// assert_eq!(decrypts(&rekeyed, Some("new passphrase")).unwrap(), "ThisIsJustaTestString");
+  /// assert!(decrypts(&rekeyed, Some("leaked passphrase")).is_err());
+  /// ```
+  pub fn rekey(encrypted: &str, old_key: Option<&str>, new_key: Option<&str>) -> Result<String> {
+    rekey_with(encrypted, old_key, new_key, &[])
+  }
+
+  /// Re-encrypts like [`rekey`] a result or an image encrypted with associated data, such as a
+  /// token ID or a filename. `aad` has to be the associated data it was encrypted with and stays
+  /// bound to the new result. 1.x results have no associated data and fail with any.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::text::{encrypts_with, EncryptOptions};
+  /// use encrypted_images::encryption::rekey::rekey_with;
+  /// use encrypted_images::decryption::text::{decrypts_with, DecryptOptions};
+  ///
+  /// let options = EncryptOptions { aad: b"token 42".to_vec(), ..EncryptOptions::default() };
+  /// let encrypted = encrypts_with("ThisIsJustaTestString", Some("leaked passphrase"), Some("authenticated"), &options).unwrap();
+  /// let rekeyed = rekey_with(&encrypted, Some("leaked passphrase"), Some("new passphrase"), b"token 42").unwrap();
+  ///
+  /// let token_42 = DecryptOptions { aad: b"token 42".to_vec(), ..DecryptOptions::default() };
+  /// assert_eq!(decrypts_with(&rekeyed, Some("new passphrase"), &token_42).unwrap(), "ThisIsJustaTestString");
+  /// ```
+  pub fn rekey_with(encrypted: &str, old_key: Option<&str>, new_key: Option<&str>, aad: &[u8]) -> Result<String> {
+    let bytes = CUSTOM_ENGINE.decode(encrypted).map_err(|_| Error::InvalidBase64)?;
+    if bytes.starts_with(PNG_SIGNATURE) {
+      let settings = ImageSettings::from_img(encrypted)?;
+      let ciphertext = decode_image_and_extract_text(encrypted)?;
+      return settings.create_img(&rekey_ciphertext(&ciphertext, old_key, new_key, aad)?);
+    }
+    rekey_ciphertext(encrypted, old_key, new_key, aad)
+  }
+
+  fn rekey_ciphertext(encoded_result: &str, old_key: Option<&str>, new_key: Option<&str>, aad: &[u8]) -> Result<String> {
+    let old_key = old_key.unwrap_or(DEFAULT_KEY);
+    let new_key = new_key.unwrap_or(DEFAULT_KEY);
+    let bytes = CUSTOM_ENGINE.decode(encoded_result).map_err(|_| Error::InvalidBase64)?;
+    if !is_envelope(&bytes) {
+      let options = DecryptOptions { aad: aad.to_vec(), ..DecryptOptions::default() };
+      let plaintext = decrypt_bytes_with(encoded_result, Some(old_key), &options)?;
+      // The 1.x default strength used the base64 encoded first 10 bytes of the input as IV
+      let iv = bytes.get(..CBC_IV_LEN).ok_or(Error::Truncated)?;
+      let deterministic = plaintext.get(..10).map(|prefix| CUSTOM_ENGINE.encode(prefix).as_bytes() == iv).unwrap_or(false);
+      return encrypt_bytes(&plaintext, Some(new_key), Some(if deterministic { "default" } else { "advanced" }));
+    }
+    let (header, _, plaintext) = open(encoded_result, old_key, aad)?;
+    match header.key {
+      KeyBlock::Passphrase { kdf, .. } => {
+        let salt = if header.suite == Suite::Aes256GcmSiv { DETERMINISTIC_SALT.to_vec() } else { generate_random_bytes(SALT_LEN) };
+        let header = Header { key: KeyBlock::Passphrase { kdf, salt }, ..header };
+        check_encryption(&header, &[new_key])?;
+        seal(&header, &SuiteKeys::derive(new_key, &header)?, &plaintext, aad)
+      }
+      KeyBlock::Recipients(slots) => {
+        let position = opened_slot(old_key, &slots).ok_or(Error::WrongKey)?;
+        let recipient = if public_key_from_pem(new_key).is_some() { Recipient::PublicKey(new_key) } else { Recipient::Passphrase(new_key) };
+        // Whoever holds the old credential may know the old content key, so it is replaced too
+        let content_key = KeyBytes::new(generate_random_bytes(CONTENT_KEY_LEN));
+        let slots = slots.into_iter().enumerate().map(|(index, slot)| match slot {
+          _ if index == position => wrap_for(recipient, &content_key),
+          Slot::X25519 { recipient_public_key, .. } => wrap_for_x25519(recipient_public_key, &content_key),
+          Slot::Passphrase { .. } => Err(Error::InvalidArgument("other passphrase recipients can't be given the new content key, remove them first")),
+        }).collect::<Result<Vec<Slot>>>()?;
+        let header = Header { key: KeyBlock::Recipients(slots), ..header };
+        check_encryption(&header, &new_passphrases(&[recipient]))?;
+        seal(&header, &SuiteKeys::expand(&content_key, header.suite)?, &plaintext, aad)
+      }
+    }
+  }
@@ -0,0 +1,78 @@
+  use std::fmt;
+  use std::ops::Deref;
+  use zeroize::{Zeroize, Zeroizing};
+
+  /// Key material that is overwritten with zeros when it is dropped.
+  pub(crate) type KeyBytes = Zeroizing<Vec<u8>>;
+
+  /// A passphrase or private key PEM that is overwritten with zeros when it is dropped and never
+  /// shows up in `Debug` output.
+  ///
+  /// It dereferences to `str`, so `Some(&secret)` is accepted wherever a key is taken as
+  /// `Option<&str>`, and plain strings convert into it with `From`. Keys derived from it inside
+  /// the crate are wiped as well.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::secret::SecretKey;
+  /// use encrypted_images::encryption::text::encrypts;
+  /// use encrypted_images::decryption::text::decrypts;
+  ///
+  /// let key = SecretKey::from("your_secret_key");
+  /// let encrypted = encrypts("ThisIsJustaTestString", Some(&key), Some("authenticated")).unwrap();
+  ///
+  /// assert_eq!(format!("{:?}", key), "SecretKey(<redacted>)");
+  /// assert_eq!(decrypts(&encrypted, Some(&key)).unwrap(), "ThisIsJustaTestString");
+  /// ```
+  #[derive(Clone, Default, PartialEq, Eq)]
+  pub struct SecretKey(String);
+
+  /// Alias for keys that are passphrases.
+  pub type Passphrase = SecretKey;
+
+  impl SecretKey {
+    /// The key as a string slice.
+    pub fn expose(&self) -> &str {
+      &self.0
+    }
+  }
+
+  impl Deref for SecretKey {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+      &self.0
+    }
+  }
+
+  impl AsRef<str> for SecretKey {
+    fn as_ref(&self) -> &str {
+      &self.0
+    }
+  }
+
+  impl From<&str> for SecretKey {
+    fn from(key: &str) -> Self {
+      SecretKey(key.to_string())
+    }
+  }
+
+  /// Takes over the string without copying it.
+  impl From<String> for SecretKey {
+    fn from(key: String) -> Self {
+      SecretKey(key)
+    }
+  }
+
+  impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      f.write_str("SecretKey(<redacted>)")
+    }
+  }
+
+  impl Drop for SecretKey {
+    fn drop(&mut self) {
+      self.0.zeroize();
+    }
+  }
@@ -0,0 +1,84 @@
+  use crate::encryption::images::ImageSettings;
+  use crate::encryption::text::{encrypt_bytes, generate_random_bytes};
+  use crate::error::{Error, Result};
+
+  /// Length of the random identifier shared by all shares of one split.
+  pub(crate) const SET_ID_LEN: usize = 8;
+  /// `index[1] || threshold[1] || set_id[8]` in front of the share bytes.
+  pub(crate) const SHARE_HEADER_LEN: usize = 2 + SET_ID_LEN;
+
+  /// Splits a secret into `count` encrypted images so that any `threshold` of them recover it.
+  ///
+  /// The secret is split with Shamir's secret sharing over GF(256): fewer than `threshold` shares
+  /// reveal nothing about it. Every share records its index, the threshold and an identifier of
+  /// the split, is encrypted with the authenticated strength under `key` and drawn as its own
+  /// image with `settings`. Recover the secret with
+  /// [`crate::decryption::shares::combine_imgs`].
+  ///
+  /// Fails with [`Error::InvalidArgument`] unless `1 <= threshold <= count`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::images::ImageSettings;
+  /// use encrypted_images::encryption::shares::split_to_imgs;
+  /// use encrypted_images::decryption::shares::combine_imgs;
+  ///
+  /// let key = Some("your_secret_key");
+  /// let images = split_to_imgs("ThisIsJustaTestString", 2, 3, key, &ImageSettings::default()).unwrap();
+  ///
+  /// assert_eq!(combine_imgs(&[&images[2], &images[0]], key).unwrap(), "ThisIsJustaTestString");
+  /// assert!(combine_imgs(&[&images[1]], key).is_err());
+  /// ```
+  pub fn split_to_imgs(secret: &str, threshold: u8, count: u8, key: Option<&str>, settings: &ImageSettings) -> Result<Vec<String>> {
+    split_bytes(secret.as_bytes(), threshold, count)?.iter()
+      .map(|share| settings.create_img(&encrypt_bytes(share, key, Some("authenticated"))?))
+      .collect()
+  }
+
+  /// Splits `secret` into `count` shares `index || threshold || set_id || share bytes`.
+  pub(crate) fn split_bytes(secret: &[u8], threshold: u8, count: u8) -> Result<Vec<Vec<u8>>> {
+    if threshold == 0 || threshold > count {
+      return Err(Error::InvalidArgument("threshold"));
+    }
+    let set_id = generate_random_bytes(SET_ID_LEN);
+    let mut shares: Vec<Vec<u8>> = (1..=count).map(|index| [&[index, threshold][..], &set_id].concat()).collect();
+    for &byte in secret {
+      // A random polynomial of degree threshold - 1 whose value at 0 is the secret byte
+      let mut coefficients = generate_random_bytes(threshold as usize);
+      coefficients[0] = byte;
+      for share in shares.iter_mut() {
+        let x = share[0];
+        let y = coefficients.iter().rev().fold(0, |y, &coefficient| gf256::add(gf256::mul(y, x), coefficient));
+        share.push(y);
+      }
+    }
+    Ok(shares)
+  }
+
+  /// Arithmetic in GF(2^8) with the AES polynomial, without secret dependent branches.
+  pub(crate) mod gf256 {
+    pub(crate) fn add(a: u8, b: u8) -> u8 {
+      a ^ b
+    }
+
+    pub(crate) fn mul(mut a: u8, mut b: u8) -> u8 {
+      let mut product = 0;
+      for _ in 0..8 {
+        product ^= a & (b & 1).wrapping_neg();
+        let carry = (a >> 7).wrapping_neg();
+        a = (a << 1) ^ (0x1b & carry);
+        b >>= 1;
+      }
+      product
+    }
+
+    /// Multiplicative inverse, `a^254`. Only called with the public, non-zero share indices.
+    pub(crate) fn inv(a: u8) -> u8 {
+      let mut result = 1;
+      for _ in 0..254 {
+        result = mul(result, a);
+      }
+      result
+    }
+  }
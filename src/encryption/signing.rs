@@ -0,0 +1,60 @@
+  use crate::decryption::images::decode_image_and_extract_text;
+  use crate::backend::{Active, Backend};
+  use crate::encryption::chunks::insert_text_chunk;
+  use crate::encryption::images::create_img;
+  use crate::encryption::keys::signing_key_from_pem;
+  use crate::error::{Error, Result};
+  use base64::{Engine as _, engine::{self, general_purpose}, alphabet};
+  const CUSTOM_ENGINE: engine::GeneralPurpose =
+    engine::GeneralPurpose::new(&alphabet::STANDARD, general_purpose::PAD);
+
+  /// Keyword of the PNG `tEXt` chunk holding `public_key[32] || signature[64]`, base64 encoded.
+  pub(crate) const SIGNATURE_KEYWORD: &str = "encrypted_images signature";
+  pub(crate) const ED25519_KEY_LEN: usize = 32;
+  pub(crate) const SIGNATURE_LEN: usize = 64;
+  /// Prefix of every signed message, so an image signature can't be passed off as a signature
+  /// of anything else made with the same key.
+  const SIGNATURE_CONTEXT: &[u8] = b"encrypted_images v2 image signature\0";
+
+  pub(crate) fn signed_message(ciphertext: &str) -> Vec<u8> {
+    [SIGNATURE_CONTEXT, ciphertext.as_bytes()].concat()
+  }
+
+  /// Creates an image like [`create_img`] and signs it with an Ed25519 private key PEM.
+  ///
+  /// The signature covers the ciphertext shown by the image and is stored together with the
+  /// signer's public key in a `tEXt` chunk of the PNG, so anyone can check who created the image
+  /// with `decryption::verification::verify_img`, without being able to decrypt it.
+  ///
+  /// Fails like [`create_img`] if the image can't be created, and with [`Error::InvalidKey`] if
+  /// `signing_key` isn't an Ed25519 private key PEM.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::keys::SigningKeyPair;
+  /// use encrypted_images::encryption::signing::create_signed_img;
+  /// use encrypted_images::decryption::verification::verify_img;
+  ///
+  /// let artist = SigningKeyPair::generate().unwrap();
+  /// let signing_key = artist.private_key_pem().unwrap();
+  /// let image = create_signed_img("ThisIsCiphertext", "h", "empty", None, None, None, None, None, None, &signing_key).unwrap();
+  ///
+  /// assert_eq!(verify_img(&image).unwrap(), artist.public_key_pem().unwrap());
+  /// ```
+  #[allow(clippy::too_many_arguments)]
+  pub fn create_signed_img(ciphertext: &str, style: &str, watermark: &str, r: Option<u8>, g: Option<u8>, b: Option<u8>, a: Option<u8>, w: Option<u32>, h: Option<u32>, signing_key: &str) -> Result<String> {
+    sign_img(&create_img(ciphertext, style, watermark, r, g, b, a, w, h)?, signing_key)
+  }
+
+  /// Signs an existing encoded image with an Ed25519 private key PEM, replacing an earlier
+  /// signature. See [`create_signed_img`].
+  pub fn sign_img(encoded_image: &str, signing_key: &str) -> Result<String> {
+    let signing_key = signing_key_from_pem(signing_key).ok_or(Error::InvalidKey)?;
+    let ciphertext = decode_image_and_extract_text(encoded_image)?;
+    let signature = Active::ed25519_sign(&signing_key, &signed_message(&ciphertext))?;
+    let block = [Active::ed25519_public_key(&signing_key)?, signature].concat();
+    let png = CUSTOM_ENGINE.decode(encoded_image).map_err(|_| Error::InvalidBase64)?;
+    let signed = insert_text_chunk(&png, SIGNATURE_KEYWORD, &CUSTOM_ENGINE.encode(block)).ok_or(Error::ImageDecode)?;
+    Ok(CUSTOM_ENGINE.encode(signed))
+  }
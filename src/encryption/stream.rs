@@ -0,0 +1,149 @@
+  use std::io::{self, Read, Write};
+  use crate::backend::{Active, Backend};
+  use crate::encryption::envelope::{Header, KeyBlock, Suite};
+  use crate::encryption::kdf::SALT_LEN;
+  use crate::encryption::policy::check_encryption;
+  use crate::encryption::secret::KeyBytes;
+  use crate::encryption::text::{associated_data, generate_random_bytes, EncryptOptions, SuiteKeys, DEFAULT_KEY, GCM_NONCE_LEN};
+
+  /// Plaintext bytes per segment. Every segment but the last holds exactly this many bytes.
+  pub(crate) const SEGMENT_LEN: usize = 64 * 1024;
+  /// Random part of the segment nonce, followed by a 32-bit segment counter and a last flag.
+  pub(crate) const NONCE_PREFIX_LEN: usize = 7;
+
+  /// Nonce of segment `counter`: `prefix[7] || counter[4] || last[1]`. The counter detects
+  /// reordered segments and the last flag detects a truncated stream.
+  pub(crate) fn segment_nonce(prefix: &[u8], counter: u32, last: bool) -> [u8; GCM_NONCE_LEN] {
+    let mut nonce = [0u8; GCM_NONCE_LEN];
+    nonce[..NONCE_PREFIX_LEN].copy_from_slice(prefix);
+    nonce[NONCE_PREFIX_LEN..GCM_NONCE_LEN - 1].copy_from_slice(&counter.to_be_bytes());
+    nonce[GCM_NONCE_LEN - 1] = last as u8;
+    nonce
+  }
+
+  /// Encrypts a stream of any size with bounded memory.
+  ///
+  /// The output is binary, not base64: an envelope header naming the streaming suite, a random
+  /// nonce prefix, the key commitment and a sequence of AES-256-GCM segments of [`SEGMENT_LEN`] plaintext bytes. Each
+  /// segment is authenticated with its position and whether it is the last one, so reordered,
+  /// dropped or truncated segments are detected by
+  /// [`crate::decryption::stream::StreamDecryptor`]. Call [`StreamEncryptor::finish`] to write
+  /// the last segment; a stream that is dropped without it will not decrypt.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use std::io::Write;
+  /// use encrypted_images::encryption::stream::StreamEncryptor;
+  ///
+  /// let mut encryptor = StreamEncryptor::new(Vec::new(), Some("your_secret_key")).unwrap();
+  /// encryptor.write_all(b"ThisIsJustaTestString").unwrap();
+  /// let encrypted = encryptor.finish().unwrap();
+  ///
+  /// assert!(encrypted.len() > 21);
+  /// ```
+  pub struct StreamEncryptor<W: Write> {
+    writer: W,
+    key: KeyBytes,
+    associated_data: Vec<u8>,
+    nonce_prefix: Vec<u8>,
+    counter: u32,
+    buffer: Vec<u8>,
+  }
+
+  impl<W: Write> StreamEncryptor<W> {
+    /// Starts a stream with the default key derivation cost and writes its header.
+    pub fn new(writer: W, key: Option<&str>) -> io::Result<Self> {
+      Self::with_options(writer, key, &EncryptOptions::default())
+    }
+
+    /// Starts a stream with the key derivation cost and associated data from `options` and writes
+    /// its header. Compression and padding are ignored, and embedded metadata is not supported and
+    /// fails.
+    pub fn with_options(mut writer: W, key: Option<&str>, options: &EncryptOptions) -> io::Result<Self> {
+      if options.metadata.is_some() {
+        return Err(invalid_input("streams can't embed metadata"));
+      }
+      let key = key.unwrap_or(DEFAULT_KEY);
+      let salt = generate_random_bytes(SALT_LEN);
+      let header = Header::new(Suite::Aes256GcmStream, KeyBlock::Passphrase { kdf: options.kdf, salt });
+      check_encryption(&header, &[key])?;
+      let keys = SuiteKeys::derive(key, &header)?;
+      let header = header.to_bytes();
+      let nonce_prefix = generate_random_bytes(NONCE_PREFIX_LEN);
+      writer.write_all(&header)?;
+      writer.write_all(&nonce_prefix)?;
+      writer.write_all(&keys.commitment)?;
+      Ok(StreamEncryptor {
+        writer,
+        key: keys.cipher,
+        associated_data: associated_data(&header, &options.aad),
+        nonce_prefix,
+        counter: 0,
+        buffer: Vec::with_capacity(SEGMENT_LEN + 1),
+      })
+    }
+
+    /// Encrypts and writes the last segment, then returns the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+      let last = std::mem::take(&mut self.buffer);
+      self.write_segment(&last, true)?;
+      self.writer.flush()?;
+      Ok(self.writer)
+    }
+
+    fn write_segment(&mut self, segment: &[u8], last: bool) -> io::Result<()> {
+      let nonce = segment_nonce(&self.nonce_prefix, self.counter, last);
+      let sealed = Active::aes_256_gcm_seal(&self.key, &nonce, &self.associated_data, segment)
+        .map_err(|_| invalid_input("segment encryption failed"))?;
+      self.writer.write_all(&sealed)?;
+      self.counter = self.counter.checked_add(1).ok_or_else(|| invalid_input("stream has too many segments"))?;
+      Ok(())
+    }
+  }
+
+  impl<W: Write> Write for StreamEncryptor<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+      // A full segment is only written once more data follows it, the last segment is written
+      // by `finish` with the last flag set.
+      let take = data.len().min(SEGMENT_LEN + 1 - self.buffer.len());
+      self.buffer.extend_from_slice(&data[..take]);
+      if self.buffer.len() > SEGMENT_LEN {
+        let segment: Vec<u8> = self.buffer.drain(..SEGMENT_LEN).collect();
+        self.write_segment(&segment, false)?;
+      }
+      Ok(take)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+      self.writer.flush()
+    }
+  }
+
+  /// Encrypts everything `reader` yields into `writer` and returns the number of plaintext bytes.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::stream::encrypt_stream;
+  /// use encrypted_images::decryption::stream::decrypt_stream;
+  ///
+  /// let key = Some("your_secret_key");
+  /// let mut encrypted = Vec::new();
+  /// encrypt_stream(&b"ThisIsJustaTestString"[..], &mut encrypted, key).unwrap();
+  ///
+  /// let mut decrypted = Vec::new();
+  /// decrypt_stream(&encrypted[..], &mut decrypted, key).unwrap();
+  /// assert_eq!(decrypted, b"ThisIsJustaTestString");
+  /// ```
+  pub fn encrypt_stream<R: Read, W: Write>(mut reader: R, writer: W, key: Option<&str>) -> io::Result<u64> {
+    let mut encryptor = StreamEncryptor::new(writer, key)?;
+    let copied = io::copy(&mut reader, &mut encryptor)?;
+    encryptor.finish()?;
+    Ok(copied)
+  }
+
+  fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+  }
+
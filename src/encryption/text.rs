@@ -4,21 +4,31 @@
   /// The `input` parameter is the text to be encrypted.
   ///
   /// The `key` parameter is an optional encryption key. If not provided, a default key is used.
+  /// Pass `Some(&secret)` for a [`crate::encryption::secret::SecretKey`] to have the passphrase
+  /// wiped from memory once it is no longer needed.
   ///
-  /// The `strength` parameter is optional security level. Is set this value can be default or
-  /// advanced.
+  /// The `strength` parameter is optional security level. Is set this value can be default,
+  /// advanced or authenticated.
   ///
   /// # Notes
   ///
   /// - This function has been updated to include timing attack protections to enhance security.
-  /// - The function uses static IV (Initialization Vector) bytes by default to generate consistant
-  ///   encrypted text output. While suitable for some situations ensure to use the advanced
-  ///   strength for highly sensative data but note that everytime you encrypt with the advanced
-  ///   method the output will look differnt. 
+  /// - By default the function encrypts deterministically with AES-256-GCM-SIV to generate
+  ///   consistant encrypted text output. No part of the input is readable from the output, but
+  ///   encrypting the same input twice reveals that the inputs were equal. Ensure to use the
+  ///   advanced strength for highly sensative data but note that everytime you encrypt with the
+  ///   advanced method the output will look differnt.
   /// - Again stressing that the default security option is not the ideal choice for scenarios requiring the 
   ///   highest level of encryption security.
   /// - Without advanced security there are potentials for comparison attacks that can occur this encryption.
   /// - Only use standard settings for novelty usage.
+  /// - The authenticated strength encrypts with AES-256-GCM under a random 96-bit nonce. Any
+  ///   modification of the ciphertext is detected on decryption. Use it for real secrets.
+  /// - The key is derived from the passphrase with scrypt, so passphrases of any length are
+  ///   supported. The advanced and authenticated strengths use a random salt, the default strength
+  ///   a fixed one so its output stays reproducible. Use [`encrypts_with`] to tune the cost.
+  /// - The advanced strength encrypts with AES-128-CBC and then computes an HMAC-SHA256 over the
+  ///   header, the IV and the ciphertext. Cipher and MAC keys are derived separately with HKDF.
   ///
   /// # Examples
   ///
@@ -56,62 +66,329 @@
   ///
   ///  assert!(encrypted.as_ref().unwrap().len() > 0);
   /// ```
-  use rand::{Rng};
-  use rand::rngs::OsRng;
-  use openssl::symm::{encrypt, Cipher};
-  use crate::encryption::text::hmac::calculate_hmac;
+  ///
+  /// Encrypt a text with authenticated AES-256-GCM:
+  ///
+  /// ```
+  /// use encrypted_images::encryption::text::encrypts;
+  ///
+  /// let input = "ThisIsJustaTestString";
+  /// let strength = "authenticated";
+  /// let encrypted = encrypts(input, Some("your_secret_key"), Some(strength));
+  ///
+  /// assert!(encrypted.as_ref().unwrap().len() > 0);
+  /// ```
   use subtle::ConstantTimeEq;
+  use crate::backend::{Active, Backend};
+  use crate::encryption::text::hmac::calculate_hmac_over;
+  use crate::encryption::kdf::{derive_key, expand_key, KdfParams, SALT_LEN};
+  use crate::encryption::envelope::{Header, KeyBlock, Suite, FLAG_DEFLATE, FLAG_METADATA, FLAG_PADDED};
+  use crate::encryption::metadata::Metadata;
+  use crate::encryption::compression::{deflate, Compression};
+  use crate::encryption::padding::{pad, Padding};
+  use crate::encryption::policy::check_encryption;
+  use crate::encryption::secret::KeyBytes;
+  use crate::encryption::recipients::{encrypt_bytes_for, unwrap_slots, Recipient};
+  use crate::error::{Error, Result};
   use base64::{Engine as _, engine::{self, general_purpose}, alphabet};
   const CUSTOM_ENGINE: engine::GeneralPurpose =
     engine::GeneralPurpose::new(&alphabet::STANDARD, general_purpose::PAD);
 
 
-  pub fn encrypts(input: &str, key: Option<&str>, strength: Option<&str>) -> Option<String> {
-    let cipher = Cipher::aes_128_cbc();
-    let key = key.unwrap_or("welovenfts");
-    let strength = strength.unwrap_or("default");
-    let iv_bytes = &input.to_string()[..10];
-    let iv: String;
-    if strength == "default" {
-      iv = CUSTOM_ENGINE.encode(iv_bytes);
-    } else {
-      let num_bytes = 10; // Adjust this to the number of random bytes you need
-      let random_bytes = generate_random_bytes(num_bytes);
-      iv = CUSTOM_ENGINE.encode(random_bytes);
+  pub fn encrypts(input: &str, key: Option<&str>, strength: Option<&str>) -> Result<String> {
+    encrypts_with(input, key, strength, &EncryptOptions::default())
+  }
+
+  /// Optional settings for [`encrypts_with`].
+  #[derive(Clone, Debug, Default)]
+  pub struct EncryptOptions {
+    /// Cost of the passphrase key derivation.
+    pub kdf: KdfParams,
+    /// Associated data, such as a token ID, a collection name or a filename. It is authenticated
+    /// but not encrypted or stored, so decrypting needs the same bytes in
+    /// `DecryptOptions::aad` and fails otherwise. Empty by default.
+    pub aad: Vec<u8>,
+    /// Compression of the plaintext, none by default. Streams are never compressed.
+    pub compression: Compression,
+    /// Padding of the plaintext to hide its length, none by default. Streams are never padded.
+    pub padding: Padding,
+    /// Creation and validity times to embed in the encrypted payload, none by default. Not
+    /// supported for streams.
+    pub metadata: Option<Metadata>,
+  }
+
+  /// Encrypts the input text like [`encrypts`], with additional settings.
+  ///
+  /// The output starts with a versioned envelope header (see [`crate::encryption::envelope`])
+  /// naming the cipher suite and the scrypt cost parameters and salt, so keys of any length
+  /// decrypt with `decrypts` alone.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::text::{encrypts_with, EncryptOptions};
+  /// use encrypted_images::encryption::kdf::KdfParams;
+  /// use encrypted_images::decryption::text::decrypts;
+  ///
+  /// let options = EncryptOptions { kdf: KdfParams { log_n: 16, ..KdfParams::default() }, ..EncryptOptions::default() };
+  /// let key = Some("a passphrase that is much longer than sixteen bytes");
+  /// let encrypted = encrypts_with("ThisIsJustaTestString", key, Some("authenticated"), &options).unwrap();
+  ///
+  /// assert_eq!(decrypts(&encrypted, key).unwrap(), "ThisIsJustaTestString");
+  /// ```
+  ///
+  /// Bind the result to a token ID:
+  ///
+  /// ```
+  /// use encrypted_images::encryption::text::{encrypts_with, EncryptOptions};
+  /// use encrypted_images::decryption::text::{decrypts_with, DecryptOptions};
+  ///
+  /// let key = Some("your_secret_key");
+  /// let options = EncryptOptions { aad: b"token 42".to_vec(), ..EncryptOptions::default() };
+  /// let encrypted = encrypts_with("ThisIsJustaTestString", key, Some("authenticated"), &options).unwrap();
+  ///
+  /// let token_42 = DecryptOptions { aad: b"token 42".to_vec(), ..DecryptOptions::default() };
+  /// let token_43 = DecryptOptions { aad: b"token 43".to_vec(), ..DecryptOptions::default() };
+  /// assert_eq!(decrypts_with(&encrypted, key, &token_42).unwrap(), "ThisIsJustaTestString");
+  /// assert!(decrypts_with(&encrypted, key, &token_43).is_err());
+  /// ```
+  pub fn encrypts_with(input: &str, key: Option<&str>, strength: Option<&str>, options: &EncryptOptions) -> Result<String> {
+    encrypt_bytes_with(input.as_bytes(), key, strength, options)
+  }
+
+  /// Encrypts arbitrary bytes, such as keys, small files or serialized messages.
+  ///
+  /// Works exactly like [`encrypts`] but takes a byte slice, so data that is not valid UTF-8
+  /// survives the round trip unchanged. Decrypt the result with
+  /// [`crate::decryption::text::decrypt_bytes`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::text::encrypt_bytes;
+  /// use encrypted_images::decryption::text::decrypt_bytes;
+  ///
+  /// let input = [0u8, 159, 146, 150, 255];
+  /// let encrypted = encrypt_bytes(&input, Some("your_secret_key"), Some("authenticated")).unwrap();
+  ///
+  /// assert_eq!(decrypt_bytes(&encrypted, Some("your_secret_key")).unwrap(), input.to_vec());
+  /// ```
+  pub fn encrypt_bytes(input: &[u8], key: Option<&str>, strength: Option<&str>) -> Result<String> {
+    encrypt_bytes_with(input, key, strength, &EncryptOptions::default())
+  }
+
+  /// Encrypts arbitrary bytes like [`encrypt_bytes`], with the settings of [`encrypts_with`].
+  pub fn encrypt_bytes_with(input: &[u8], key: Option<&str>, strength: Option<&str>, options: &EncryptOptions) -> Result<String> {
+    let key = key.unwrap_or(DEFAULT_KEY);
+    let suite = match strength.unwrap_or("default") {
+      "default" => Suite::Aes256GcmSiv,
+      "authenticated" => Suite::Aes256Gcm,
+      _ => Suite::Aes128CbcHmacSha256,
+    };
+    let salt = if suite == Suite::Aes256GcmSiv { DETERMINISTIC_SALT.to_vec() } else { generate_random_bytes(SALT_LEN) };
+    let mut header = Header::new(suite, KeyBlock::Passphrase { kdf: options.kdf, salt });
+    check_encryption(&header, &[key])?;
+    let keys = SuiteKeys::derive(key, &header)?;
+    let with_metadata;
+    let input = match options.metadata {
+      Some(metadata) => {
+        header.flags |= FLAG_METADATA;
+        with_metadata = [&metadata.to_bytes()[..], input].concat();
+        &with_metadata[..]
+      }
+      None => input,
+    };
+    let compressed = match options.compression {
+      Compression::Deflate => Some(deflate(input)?).filter(|compressed| compressed.len() < input.len()),
+      Compression::None => None,
+    };
+    if compressed.is_some() {
+      header.flags |= FLAG_DEFLATE;
     }
-    let mut padded_key = key.as_bytes().to_vec();
+    let input = compressed.as_deref().unwrap_or(input);
+    let padded = pad(input, options.padding, suite == Suite::Aes256GcmSiv)?;
+    if padded.is_some() {
+      header.flags |= FLAG_PADDED;
+    }
+    seal(&header, &keys, padded.as_deref().unwrap_or(input), &options.aad)
+  }
+
+  /// Encrypts the input text for the holder of an X25519 private key.
+  ///
+  /// `recipient` is the public key PEM of a [`crate::encryption::keys::KeyPair`]. A random
+  /// content key encrypts the input with AES-256-GCM and is wrapped with a key agreed between a
+  /// fresh ephemeral key pair and the recipient's key, so only the recipient's private key PEM
+  /// decrypts the result. Pass it to `decrypts` in place of a passphrase.
+  ///
+  /// Fails with [`Error::InvalidKey`] if `recipient` is not an X25519 public key PEM.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::encryption::keys::KeyPair;
+  /// use encrypted_images::encryption::text::encrypts_to;
+  /// use encrypted_images::decryption::text::decrypts;
+  ///
+  /// let collector = KeyPair::generate().unwrap();
+  /// let encrypted = encrypts_to("ThisIsJustaTestString", &collector.public_key_pem().unwrap()).unwrap();
+  ///
+  /// assert_eq!(decrypts(&encrypted, Some(&collector.private_key_pem().unwrap())).unwrap(), "ThisIsJustaTestString");
+  /// assert!(decrypts(&encrypted, None).is_err());
+  /// ```
+  pub fn encrypts_to(input: &str, recipient: &str) -> Result<String> {
+    encrypt_bytes_to(input.as_bytes(), recipient)
+  }
+
+  /// Encrypts arbitrary bytes for the holder of an X25519 private key, like [`encrypts_to`].
+  pub fn encrypt_bytes_to(input: &[u8], recipient: &str) -> Result<String> {
+    encrypt_bytes_for(input, &[Recipient::PublicKey(recipient)])
+  }
+
+  /// Encrypts `input` with the suite named by `header` and returns the encoded envelope.
+  pub(crate) fn seal(header: &Header, keys: &SuiteKeys, input: &[u8], aad: &[u8]) -> Result<String> {
+    let suite = header.suite;
+    let header = header.to_bytes();
+    let associated_data = associated_data(&header, aad);
+    let body = match suite {
+      Suite::Aes256GcmSiv => [keys.commitment.clone(), seal_deterministic(&keys.cipher, &associated_data, input)?].concat(),
+      Suite::Aes256Gcm => seal_gcm(keys, &associated_data, input)?,
+      Suite::Aes128CbcHmacSha256 => {
+        let iv = generate_random_bytes(CBC_IV_LEN);
+        let ciphertext = Active::aes_128_cbc_encrypt(&keys.cipher, &iv, input)?;
+        let hmac = calculate_hmac_over(&[&associated_data, &iv, &ciphertext], &keys.mac)?;
+        [iv, hmac, ciphertext].concat()
+      }
+      Suite::Aes256GcmStream => return Err(Error::UnsupportedFormat),
+    };
+    let mut result = header;
+    result.extend_from_slice(&body);
+    Ok(CUSTOM_ENGINE.encode(&result))
+  }
+
+  /// AES-256-GCM under a random nonce, returns `commitment[32] || nonce[12] || ciphertext ||
+  /// tag[16]`.
+  pub(crate) fn seal_gcm(keys: &SuiteKeys, associated_data: &[u8], input: &[u8]) -> Result<Vec<u8>> {
+    let nonce = generate_random_bytes(GCM_NONCE_LEN);
+    let sealed = Active::aes_256_gcm_seal(&keys.cipher, &nonce, associated_data, input)?;
+    Ok([keys.commitment.clone(), nonce, sealed].concat())
+  }
+
+  /// The data authenticated along with the body: the header, followed by the caller's associated
+  /// data with its length if there is any. The header is self delimiting and the length prefix
+  /// keeps the associated data apart from the IV and ciphertext covered by the HMAC.
+  pub(crate) fn associated_data(header: &[u8], aad: &[u8]) -> Vec<u8> {
+    if aad.is_empty() {
+      return header.to_vec();
+    }
+    [header, &(aad.len() as u64).to_be_bytes(), aad].concat()
+  }
+
+  /// Keys of one envelope. The scrypt output is only used as input keying material for HKDF, so
+  /// the cipher and the MAC never share key bytes.
+  ///
+  /// AES-GCM and AES-GCM-SIV are not key committing on their own: a ciphertext can be crafted
+  /// that authenticates under two different keys. Their bodies therefore start with
+  /// `commitment`, a value expanded from the same master key as the cipher key, which only the
+  /// right key reproduces. The HMAC of the CBC suite already commits to the key.
+  pub(crate) struct SuiteKeys {
+    pub(crate) cipher: KeyBytes,
+    pub(crate) mac: KeyBytes,
+    pub(crate) commitment: Vec<u8>,
+  }
+
+  const MASTER_KEY_LEN: usize = 32;
+  /// Length of the key commitment in front of AES-GCM and AES-GCM-SIV bodies and streams.
+  pub(crate) const COMMITMENT_LEN: usize = 32;
+  const CIPHER_KEY_INFO: &[u8] = b"encrypted_images v2 cipher key";
+  const MAC_KEY_INFO: &[u8] = b"encrypted_images v2 mac key";
+  const COMMITMENT_INFO: &[u8] = b"encrypted_images v2 key commitment";
+
+  impl SuiteKeys {
+    /// Derives the keys of `header` from `credential`, see [`master_key`].
+    pub(crate) fn derive(credential: &str, header: &Header) -> Result<SuiteKeys> {
+      SuiteKeys::expand(&master_key(credential, header)?, header.suite)
+    }
+
+    pub(crate) fn expand(master: &[u8], suite: Suite) -> Result<SuiteKeys> {
+      Ok(SuiteKeys {
+        cipher: expand_key(master, CIPHER_KEY_INFO, suite.key_len())?,
+        mac: expand_key(master, MAC_KEY_INFO, HMAC_LEN)?,
+        commitment: expand_key(master, COMMITMENT_INFO, COMMITMENT_LEN)?.to_vec(),
+      })
+    }
+
+    /// Splits the key commitment off the front of `body` and compares it in constant time, so a
+    /// body committed to another key is rejected before anything is decrypted.
+    pub(crate) fn check_commitment<'a>(&self, body: &'a [u8]) -> Result<&'a [u8]> {
+      if body.len() < COMMITMENT_LEN {
+        return Err(Error::Truncated);
+      }
+      let (commitment, rest) = body.split_at(COMMITMENT_LEN);
+      if commitment.ct_eq(&self.commitment).unwrap_u8() != 1 {
+        return Err(Error::MacMismatch);
+      }
+      Ok(rest)
+    }
+  }
+
+  /// The key the suite keys of `header` are expanded from. `credential` is a passphrase, or an
+  /// X25519 private key PEM for envelopes encrypted to a public key.
+  pub(crate) fn master_key(credential: &str, header: &Header) -> Result<KeyBytes> {
+    match &header.key {
+      KeyBlock::Passphrase { kdf, salt } => derive_key(credential, salt, kdf, MASTER_KEY_LEN),
+      KeyBlock::Recipients(slots) => unwrap_slots(credential, slots),
+    }
+  }
+
+  /// Key used when none is given. Forbid it with `SecurityPolicy::forbid_default_key`.
+  pub(crate) const DEFAULT_KEY: &str = "welovenfts";
+  /// Salt of the deterministic suite. A random salt would make every output different.
+  pub(crate) const DETERMINISTIC_SALT: [u8; SALT_LEN] = *b"encrypted_images";
+  const SIV_NONCE: [u8; GCM_NONCE_LEN] = [0u8; GCM_NONCE_LEN];
+  pub(crate) const CBC_IV_LEN: usize = 16;
+  pub(crate) const HMAC_LEN: usize = 32;
+  pub(crate) const GCM_NONCE_LEN: usize = 12;
+  pub(crate) const GCM_TAG_LEN: usize = 16;
+
+  /// AES-GCM-SIV is misuse resistant: reusing the nonce only reveals whether two inputs were
+  /// equal, which is exactly what a reproducible output has to reveal anyway.
+  pub(crate) fn seal_deterministic(key: &[u8], aad: &[u8], input: &[u8]) -> Result<Vec<u8>> {
+    Active::aes_256_gcm_siv_seal(key, &SIV_NONCE, aad, input)
+  }
+
+  pub(crate) fn open_deterministic(key: &[u8], aad: &[u8], body: &[u8]) -> Result<Vec<u8>> {
+    if body.len() < GCM_TAG_LEN {
+      return Err(Error::Truncated);
+    }
+    Active::aes_256_gcm_siv_open(key, &SIV_NONCE, aad, body)
+  }
+
+  /// Zero pads or truncates the key to the 16 bytes used by the 1.x layout.
+  pub(crate) fn legacy_key(key: &str) -> KeyBytes {
+    let mut padded_key = KeyBytes::new(key.as_bytes().to_vec());
     while padded_key.len() < 16 {
         padded_key.push(b'\0');
     }
     padded_key.truncate(16);
-    let ciphertext = encrypt(cipher, &padded_key, Some(iv.as_bytes()), input.as_bytes()).unwrap();
-    let hmac = calculate_hmac(&ciphertext, &padded_key);
-    if hmac.ct_eq(&calculate_hmac(&ciphertext, &padded_key)).unwrap_u8() == 1 {
-        let mut result = iv.into_bytes();
-        result.extend_from_slice(&hmac);
-        result.extend_from_slice(&ciphertext);
-        let encoded_result = CUSTOM_ENGINE.encode(&result);
-        Some(encoded_result)
-    } else {
-        println!("Encryption HMAC validation failed");
-        None
-    }
+    padded_key
   }
+
   pub mod hmac {
-    pub(crate) fn calculate_hmac(data: &[u8], key: &[u8]) -> Vec<u8> {
-      use openssl::hash::MessageDigest;
-      use openssl::pkey::PKey;
-      use openssl::sign::Signer;
-      let pkey = PKey::hmac(key).unwrap();
-      let mut signer = Signer::new(MessageDigest::sha256(), &pkey).unwrap();
-      signer.update(data).unwrap();
-      signer.sign_to_vec().unwrap()
+    use crate::error::Result;
+
+    pub(crate) fn calculate_hmac(data: &[u8], key: &[u8]) -> Result<Vec<u8>> {
+      calculate_hmac_over(&[data], key)
+    }
+
+    /// HMAC-SHA256 over the concatenation of `parts`.
+    pub(crate) fn calculate_hmac_over(parts: &[&[u8]], key: &[u8]) -> Result<Vec<u8>> {
+      use crate::backend::{Active, Backend};
+      Active::hmac_sha256(key, parts)
     }
   }
-  fn generate_random_bytes(num_bytes: usize) -> Vec<u8> {
-    let mut rng = OsRng;
+  pub(crate) fn generate_random_bytes(num_bytes: usize) -> Vec<u8> {
     let mut random_bytes = vec![0u8; num_bytes];
-    rng.fill(&mut random_bytes[..]);
+    Active::fill_random(&mut random_bytes);
     random_bytes
   }
 
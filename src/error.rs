@@ -0,0 +1,152 @@
+  use std::fmt;
+  use std::io;
+  #[cfg(feature = "openssl")]
+  use openssl::error::ErrorStack;
+
+  /// Everything that can go wrong in this crate.
+  ///
+  /// Functions return it instead of printing, so callers decide what to report. Authentication
+  /// failures are deliberately coarse: a wrong passphrase and a modified ciphertext both show up
+  /// as [`Error::MacMismatch`], as telling them apart would require decrypting unauthenticated
+  /// data.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use encrypted_images::Error;
+  /// use encrypted_images::encryption::text::encrypts;
+  /// use encrypted_images::decryption::text::decrypts;
+  ///
+  /// let encrypted = encrypts("ThisIsJustaTestString", Some("your_secret_key"), Some("authenticated")).unwrap();
+  ///
+  /// assert!(matches!(decrypts(&encrypted, Some("another_key")), Err(Error::MacMismatch)));
+  /// assert!(matches!(decrypts("not base64!", None), Err(Error::InvalidBase64)));
+  /// ```
+  #[derive(Debug)]
+  #[non_exhaustive]
+  pub enum Error {
+    /// The cipher or a key block is not valid base64.
+    InvalidBase64,
+    /// The cipher is shorter than its format requires.
+    Truncated,
+    /// The cipher failed authentication: it was modified, the associated data differs or the
+    /// key is wrong.
+    MacMismatch,
+    /// The key opens none of the recipient slots of the cipher.
+    WrongKey,
+    /// The cipher uses a version, suite, flag or key block this version of the crate doesn't
+    /// support, or a function was given a cipher of the wrong kind.
+    UnsupportedFormat,
+    /// The decrypted text is not valid UTF-8.
+    InvalidUtf8,
+    /// The embedded metadata says the cipher is not valid yet.
+    NotYetValid,
+    /// The embedded metadata says the cipher has expired.
+    Expired,
+    /// The compressed plaintext is larger than `DecryptOptions::max_decompressed_len`.
+    DecompressionLimit,
+    /// The input is larger than `DecryptOptions::max_input_len`, or an image is larger than
+    /// `MAX_IMAGE_DIMENSION` pixels in either direction.
+    TooLarge,
+    /// The authenticated plaintext doesn't have the structure its header announces.
+    InvalidPayload,
+    /// The image is not a PNG or its pixels don't hold a cipher.
+    ImageDecode,
+    /// The image could not be encoded as PNG.
+    ImageEncode,
+    /// A custom watermark is not a base64 encoded image or lacks its `a`, `w` and `h` settings.
+    InvalidWatermark,
+    /// A PEM doesn't hold a key of the required type.
+    InvalidKey,
+    /// An argument is out of range, with a short description of which.
+    InvalidArgument(&'static str),
+    /// The image doesn't carry a signature.
+    MissingSignature,
+    /// The signature of the image doesn't match its cipher or the expected signer.
+    SignatureMismatch,
+    /// Too few shares, or shares that don't belong to the same secret.
+    InvalidShares,
+    /// The keystore has no key with this name.
+    KeyNotFound,
+    /// The keystore already has a key with this name.
+    KeyExists,
+    /// The installed `SecurityPolicy` forbids the key, passphrase or settings, with a short
+    /// description of the rule.
+    PolicyViolation(&'static str),
+    /// OpenSSL failed. Only with the `openssl` backend, the `rust-crypto` backend reports
+    /// failures with the variants above.
+    #[cfg(feature = "openssl")]
+    Crypto(ErrorStack),
+    /// Reading or writing a file failed.
+    Io(io::Error),
+  }
+
+  /// Result type of this crate.
+  pub type Result<T> = std::result::Result<T, Error>;
+
+  impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      match self {
+        Error::InvalidBase64 => f.write_str("invalid base64"),
+        Error::Truncated => f.write_str("cipher is truncated"),
+        Error::MacMismatch => f.write_str("authentication failed, the key is wrong or the cipher was modified"),
+        Error::WrongKey => f.write_str("the key opens none of the recipient slots"),
+        Error::UnsupportedFormat => f.write_str("unsupported cipher format"),
+        Error::InvalidUtf8 => f.write_str("decrypted text is not valid UTF-8"),
+        Error::NotYetValid => f.write_str("cipher is not valid yet"),
+        Error::Expired => f.write_str("cipher has expired"),
+        Error::DecompressionLimit => f.write_str("decompressed plaintext exceeds the limit"),
+        Error::TooLarge => f.write_str("input exceeds the size limit"),
+        Error::InvalidPayload => f.write_str("invalid decrypted payload"),
+        Error::ImageDecode => f.write_str("image doesn't hold a cipher"),
+        Error::ImageEncode => f.write_str("image encoding failed"),
+        Error::InvalidWatermark => f.write_str("invalid watermark"),
+        Error::InvalidKey => f.write_str("invalid key"),
+        Error::InvalidArgument(message) => write!(f, "invalid argument: {}", message),
+        Error::MissingSignature => f.write_str("image is not signed"),
+        Error::SignatureMismatch => f.write_str("signature doesn't match"),
+        Error::InvalidShares => f.write_str("shares don't combine to a secret"),
+        Error::KeyNotFound => f.write_str("no key with this name"),
+        Error::KeyExists => f.write_str("a key with this name already exists"),
+        Error::PolicyViolation(rule) => write!(f, "forbidden by the security policy: {}", rule),
+        #[cfg(feature = "openssl")]
+        Error::Crypto(error) => write!(f, "openssl: {}", error),
+        Error::Io(error) => write!(f, "io: {}", error),
+      }
+    }
+  }
+
+  impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+      match self {
+        #[cfg(feature = "openssl")]
+        Error::Crypto(error) => Some(error),
+        Error::Io(error) => Some(error),
+        _ => None,
+      }
+    }
+  }
+
+  #[cfg(feature = "openssl")]
+  impl From<ErrorStack> for Error {
+    fn from(error: ErrorStack) -> Self {
+      Error::Crypto(error)
+    }
+  }
+
+  impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+      Error::Io(error)
+    }
+  }
+
+  /// Lets stream readers and writers report crate errors. The original error is kept inside and
+  /// can be recovered with `io::Error::into_inner`.
+  impl From<Error> for io::Error {
+    fn from(error: Error) -> Self {
+      match error {
+        Error::Io(error) => error,
+        error => io::Error::new(io::ErrorKind::InvalidData, error),
+      }
+    }
+  }
@@ -1,6 +1,10 @@
   pub mod char_mappings;
   pub mod encryption;
   pub mod decryption;
+  pub mod error;
+  mod backend;
+
+  pub use error::{Error, Result};
 
 
   #[cfg(test)]
@@ -43,11 +47,11 @@
       // Your encryption key (replace with the actual key you use)
       let key = Some("your_secret_key");
       // Encrypt the input
-      let encrypted = encrypts(input, key.clone(), None);
+      let encrypted = encrypts(input, key, None);
       // Decrypt the encrypted data
       let decrypted = decrypts(encrypted.as_ref().unwrap(), key);
       // Assert that decryption matches the original input
-      assert_eq!(decrypted, Some(input.to_string()));
+      assert_eq!(decrypted.unwrap(), input);
     }
 
     #[test]
@@ -59,26 +63,36 @@
       // Decrypt the encrypted data without a key
       let decrypted = decrypts(encrypted.as_ref().unwrap(), None);
       // Assert that decryption matches the original input
-      assert_eq!(decrypted, Some(input.to_string()));
+      assert_eq!(decrypted.unwrap(), input);
     }
 
     #[test]
+    #[ignore = "streams 1.8 GB, run with `cargo test --release -- --ignored`"]
     fn test_maximum_length() {
-      // Your encryption key
-      let key = Some("your_secret_key");
-  
-      let mut length = 1844674406; // Start with an initial length
-      let max_length = 1844674407; // Set the maximum length
-      while length <= max_length {
-        let encrypted = encrypts(&"A".repeat(length), key.clone(), None);
-        let decrypted = decrypts(encrypted.as_ref().unwrap(), key.clone());
+      use std::io::{self, Read, Write};
+      use crate::encryption::stream::StreamEncryptor;
+      use crate::decryption::stream::StreamDecryptor;
 
-        if decrypted.is_none() {
-            println!("Maximum size: {} characters", length);
-            break;
+      // Counts the decrypted bytes instead of keeping them, so memory stays bounded
+      struct CountingSink(u64);
+      impl Write for CountingSink {
+        fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+          self.0 += data.len() as u64;
+          Ok(data.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+          Ok(())
         }
-        length += 1;
       }
+
+      // Your encryption key
+      let key = Some("your_secret_key");
+      let length = 1844674407; // Inputs this large are streamed instead of held in memory
+      let decryptor = StreamDecryptor::new(CountingSink(0), key);
+      let mut encryptor = StreamEncryptor::new(decryptor, key).unwrap();
+      let copied = io::copy(&mut io::repeat(b'A').take(length), &mut encryptor).unwrap();
+      let decrypted = encryptor.finish().unwrap().finish().unwrap();
+      assert_eq!((copied, decrypted.0), (length, length));
     }
 
     #[test]
@@ -96,16 +110,16 @@
 
     #[test]
     fn test_minimum_length() {
-        // minimum 10 characters required to encrypt
+        // inputs of any length can be encrypted, 10 characters was the 1.x minimum
         let input = "aaaaaaaaaa";
         // Your encryption key
         let key = Some("your_secret_key");
         // Encrypt the input
-        let encrypted = encrypts(input, key.clone(), None);
+        let encrypted = encrypts(input, key, None);
         // Decrypt the encrypted data
         let decrypted = decrypts(encrypted.as_ref().unwrap(), key);
         // Assert that decryption matches the original input
-        assert_eq!(decrypted, Some(input.to_string()));
+        assert_eq!(decrypted.unwrap(), input);
     }
 
 #[test]
@@ -127,7 +141,7 @@ fn test_create_img_and_decode() {
     println!("Extracted Text: {:?}", extracted_text);
 
     // Check if extracted text matches the original ciphertext
-    assert_eq!(extracted_text, Some(ciphertext.to_string()));
+    assert_eq!(extracted_text.unwrap(), ciphertext);
 }
 
     #[test]
@@ -137,12 +151,921 @@ fn test_create_img_and_decode() {
       // Your encryption key (replace with the actual key you use)
       let key = Some("your_secret_key");
       // Encrypt the input
-      let encrypted = encrypts(input, key.clone(), None);
+      let encrypted = encrypts(input, key, None);
       // Print the encrypted string for testing
       println!("Encrypted: {}", encrypted.as_ref().unwrap());
       // Decrypt the encrypted data
       let decrypted = decrypts(encrypted.as_ref().unwrap(), key);
       // Assert that decryption matches the original input
-      assert_eq!(decrypted, Some(input.to_string()));
+      assert_eq!(decrypted.unwrap(), input);
+    }
+
+    #[test]
+    fn test_encrypts_decrypts_authenticated() {
+      let input = "ThisIsJustaTestString";
+      let key = Some("your_secret_key");
+      let encrypted = encrypts(input, key, Some("authenticated")).unwrap();
+      // A fresh nonce is used every time
+      assert_ne!(encrypts(input, key, Some("authenticated")).unwrap(), encrypted);
+      assert_eq!(decrypts(&encrypted, key).unwrap(), input);
+      assert!(decrypts(&encrypted, Some("wrong_secret_key")).is_err());
+    }
+
+    #[test]
+    fn test_authenticated_detects_tampering() {
+      use base64::{Engine as _, engine::general_purpose::STANDARD};
+      let key = Some("your_secret_key");
+      let encrypted = encrypts("ThisIsJustaTestString", key, Some("authenticated")).unwrap();
+      let mut bytes = STANDARD.decode(&encrypted).unwrap();
+      let last = bytes.len() - 20;
+      bytes[last] ^= 1;
+      assert!(decrypts(&STANDARD.encode(&bytes), key).is_err());
+    }
+
+    #[test]
+    fn test_long_keys_round_trip() {
+      let input = "ThisIsJustaTestString";
+      let key = Some("a passphrase that is a good deal longer than sixteen bytes");
+      for strength in [None, Some("advanced"), Some("authenticated")] {
+        let encrypted = encrypts(input, key, strength).unwrap();
+        assert_eq!(decrypts(&encrypted, key).unwrap(), input);
+      }
+      let encrypted = encrypts(input, key, Some("advanced")).unwrap();
+      assert!(decrypts(&encrypted, Some("a passphrase that is a good deal longer")).is_err());
+    }
+
+    #[test]
+    fn test_decrypts_legacy_advanced() {
+      let encrypted = "OWFNTGpvaGFMbWtTUkE9PcjB/klKI3ix+Z0uVuYbd3zRqaTjMgxotQu4hz1FRSfPWRQMOBhLSI6+KFPl8qldeCPoUYvezvVMOScWll9OzCA=";
+      assert_eq!(decrypts(encrypted, Some("16characterslong")).unwrap(), "This Is Plain Text");
+    }
+
+    #[test]
+    fn test_default_is_deterministic_without_leaking_prefix() {
+      use base64::{Engine as _, engine::general_purpose::STANDARD};
+      let input = "ThisIsJustaTestString";
+      let encrypted = encrypts(input, None, None).unwrap();
+      assert_eq!(encrypts(input, None, None).unwrap(), encrypted.clone());
+      assert_ne!(encrypts("ThisIsJustaTestStrinG", None, None).unwrap(), encrypted);
+      let bytes = STANDARD.decode(&encrypted).unwrap();
+      let prefix = STANDARD.encode(&input[..10]);
+      assert!(!bytes.windows(prefix.len()).any(|window| window == prefix.as_bytes()));
+      assert!(!encrypted.contains(&prefix));
+      assert_eq!(decrypts(&encrypted, None).unwrap(), input);
+    }
+
+    #[test]
+    fn test_decrypts_legacy_default() {
+      let encrypted = "VkdocGN5QkpjeUJRYkE9PZNY2MOW01NWpSxCtFG6acHuAWun+CElPQ/IIwd0gy+D+IiBqB/5+qo8Jr9bMBOwoih3amCtjXlkAlRKHX5fhqI=";
+      assert_eq!(decrypts(encrypted, None).unwrap(), "This Is Plain Text");
+    }
+
+    #[test]
+    fn test_envelope_header() {
+      use crate::encryption::envelope::{read_header, Suite, MAGIC, VERSION};
+      use base64::{Engine as _, engine::general_purpose::STANDARD};
+      let key = Some("your_secret_key");
+      let encrypted = encrypts("ThisIsJustaTestString", key, Some("advanced")).unwrap();
+      let header = read_header(&encrypted).unwrap();
+      assert_eq!((header.version, header.suite, header.flags), (VERSION, Suite::Aes128CbcHmacSha256, 0));
+      assert_eq!(read_header(&encrypts("ThisIsJustaTestString", key, None).unwrap()).unwrap().suite, Suite::Aes256GcmSiv);
+      // The 1.x layout has no header
+      assert!(read_header("VkdocGN5QkpjeUJRYkE9PZNY2MOW01NWpSxCtFG6acHuAWun+CElPQ/IIwd0gy+D+IiBqB/5+qo8Jr9bMBOwoih3amCtjXlkAlRKHX5fhqI=").is_err());
+
+      let authenticated = STANDARD.decode(encrypts("ThisIsJustaTestString", key, Some("authenticated")).unwrap()).unwrap();
+      // Unknown versions and flags are refused
+      for position in [MAGIC.len(), MAGIC.len() + 2] {
+        let mut bytes = authenticated.clone();
+        bytes[position] ^= 0x80;
+        assert!(decrypts(&STANDARD.encode(&bytes), key).is_err());
+      }
+    }
+
+    #[test]
+    fn test_advanced_detects_iv_tampering() {
+      use base64::{Engine as _, engine::general_purpose::STANDARD};
+      let key = Some("your_secret_key");
+      let encrypted = encrypts("ThisIsJustaTestString", key, Some("advanced")).unwrap();
+      let bytes = STANDARD.decode(&encrypted).unwrap();
+      // The IV directly follows the 29 byte header
+      let mut tampered = bytes.clone();
+      tampered[29] ^= 1;
+      assert!(decrypts(&STANDARD.encode(&tampered), key).is_err());
+      assert_eq!(decrypts(&encrypted, key).unwrap(), "ThisIsJustaTestString");
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+      use crate::encryption::text::encrypt_bytes;
+      use crate::decryption::text::{decrypt_bytes, decrypts_strict};
+      let input: Vec<u8> = (0..=255u8).rev().collect();
+      let key = Some("your_secret_key");
+      for strength in [None, Some("advanced"), Some("authenticated")] {
+        let encrypted = encrypt_bytes(&input, key, strength).unwrap();
+        assert_eq!(decrypt_bytes(&encrypted, key).unwrap(), input.clone());
+        assert!(decrypts_strict(&encrypted, key).is_err());
+      }
+    }
+
+    #[test]
+    fn test_short_and_multibyte_inputs() {
+      let key = Some("your_secret_key");
+      let inputs = ["", "a", "é", "🦀", "naïve café", "ThisIsJust🦀aTestString", "日本語のテキスト"];
+      for input in inputs {
+        for strength in [None, Some("advanced"), Some("authenticated")] {
+          let encrypted = encrypts(input, key, strength).unwrap();
+          assert_eq!(decrypts(&encrypted, key).unwrap(), input);
+        }
+      }
+    }
+
+    #[test]
+    fn test_stream_round_trip() {
+      use crate::encryption::stream::encrypt_stream;
+      use crate::decryption::stream::decrypt_stream;
+      let key = Some("your_secret_key");
+      // Empty, exactly one segment, and a few segments with a partial last one
+      for length in [0, 64 * 1024, 3 * 64 * 1024 + 5] {
+        let input: Vec<u8> = (0..length).map(|i| (i % 251) as u8).collect();
+        let mut encrypted = Vec::new();
+        assert_eq!(encrypt_stream(&input[..], &mut encrypted, key).unwrap(), length as u64);
+        let mut decrypted = Vec::new();
+        assert_eq!(decrypt_stream(&encrypted[..], &mut decrypted, key).unwrap(), length as u64);
+        assert_eq!(decrypted, input);
+        assert!(decrypt_stream(&encrypted[..], &mut Vec::new(), Some("wrong_secret_key")).is_err());
+      }
+    }
+
+    #[test]
+    fn test_stream_detects_truncation_and_reordering() {
+      use crate::encryption::stream::encrypt_stream;
+      use crate::decryption::stream::decrypt_stream;
+      let key = Some("your_secret_key");
+      let input = vec![b'A'; 3 * 64 * 1024 + 5];
+      let mut encrypted = Vec::new();
+      encrypt_stream(&input[..], &mut encrypted, key).unwrap();
+      // 29 byte header, 7 byte nonce prefix and 32 byte key commitment, then segments of 64 KiB
+      // plus a 16 byte tag
+      let start = 29 + 7 + 32;
+      let segment = 64 * 1024 + 16;
+
+      // Dropping the last segment, or cutting the stream at a segment boundary
+      assert!(decrypt_stream(&encrypted[..start + 3 * segment], &mut Vec::new(), key).is_err());
+      assert!(decrypt_stream(&encrypted[..start + segment], &mut Vec::new(), key).is_err());
+      assert!(decrypt_stream(&encrypted[..encrypted.len() - 1], &mut Vec::new(), key).is_err());
+
+      // Swapping two segments
+      let mut reordered = encrypted.clone();
+      let (first, second) = reordered[start..start + 2 * segment].split_at_mut(segment);
+      first.swap_with_slice(second);
+      assert!(decrypt_stream(&reordered[..], &mut Vec::new(), key).is_err());
+
+      // Flipping a bit in the header or in a segment
+      for position in [8, start + segment + 1] {
+        let mut tampered = encrypted.clone();
+        tampered[position] ^= 1;
+        assert!(decrypt_stream(&tampered[..], &mut Vec::new(), key).is_err());
+      }
+    }
+
+    #[test]
+    fn test_encrypts_to_recipient() {
+      use crate::encryption::keys::KeyPair;
+      use crate::encryption::text::encrypts_to;
+      let collector = KeyPair::generate().unwrap();
+      let private_key = collector.private_key_pem().unwrap();
+      let encrypted = encrypts_to("ThisIsJustaTestString", &collector.public_key_pem().unwrap()).unwrap();
+      assert_eq!(decrypts(&encrypted, Some(&private_key)).unwrap(), "ThisIsJustaTestString");
+      // Neither another private key nor a passphrase opens it
+      let other = KeyPair::generate().unwrap().private_key_pem().unwrap();
+      assert!(decrypts(&encrypted, Some(&other)).is_err());
+      assert!(decrypts(&encrypted, None).is_err());
+      // Private keys aren't accepted as recipients
+      assert!(encrypts_to("ThisIsJustaTestString", &private_key).is_err());
+    }
+
+    #[test]
+    fn test_key_pair_save_and_load() {
+      use crate::encryption::keys::{load_public_key, KeyPair};
+      let directory = std::env::temp_dir().join(format!("encrypted_images_keys_{}", std::process::id()));
+      std::fs::create_dir_all(&directory).unwrap();
+      let (private_path, public_path) = (directory.join("collector.pem"), directory.join("collector.pub.pem"));
+      let collector = KeyPair::generate().unwrap();
+      collector.save(&private_path, &public_path).unwrap();
+      let loaded = KeyPair::load(&private_path).unwrap();
+      assert_eq!(loaded.public_key_pem().unwrap(), collector.public_key_pem().unwrap());
+      assert_eq!(load_public_key(&public_path).unwrap(), collector.public_key_pem().unwrap());
+      assert!(KeyPair::load(&public_path).is_err());
+      #[cfg(unix)]
+      {
+        use std::os::unix::fs::PermissionsExt;
+        // Saving over a world readable file restricts it again
+        std::fs::set_permissions(&private_path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        collector.save(&private_path, &public_path).unwrap();
+        assert_eq!(std::fs::metadata(&private_path).unwrap().permissions().mode() & 0o777, 0o600);
+      }
+      std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_multiple_recipients() {
+      use crate::encryption::keys::KeyPair;
+      use crate::encryption::recipients::{add_recipient, encrypts_for, remove_recipient, Recipient};
+      let input = "ThisIsJustaTestString";
+      let collector = KeyPair::generate().unwrap();
+      let (public_key, private_key) = (collector.public_key_pem().unwrap(), collector.private_key_pem().unwrap());
+      let encrypted = encrypts_for(input, &[Recipient::Passphrase("artist passphrase"), Recipient::PublicKey(&public_key)]).unwrap();
+      assert_eq!(decrypts(&encrypted, Some("artist passphrase")).unwrap(), input);
+      assert_eq!(decrypts(&encrypted, Some(&private_key)).unwrap(), input);
+      assert!(decrypts(&encrypted, Some("gallery passphrase")).is_err());
+
+      let added = add_recipient(&encrypted, &private_key, Recipient::Passphrase("gallery passphrase")).unwrap();
+      assert_eq!(decrypts(&added, Some("gallery passphrase")).unwrap(), input);
+      assert_eq!(decrypts(&added, Some("artist passphrase")).unwrap(), input);
+      assert!(add_recipient(&encrypted, "wrong passphrase", Recipient::Passphrase("gallery passphrase")).is_err());
+
+      let removed = remove_recipient(&added, "gallery passphrase", Recipient::PublicKey(&public_key)).unwrap();
+      assert!(decrypts(&removed, Some(&private_key)).is_err());
+      assert_eq!(decrypts(&removed, Some("artist passphrase")).unwrap(), input);
+      // The last slot can't be removed
+      let single = remove_recipient(&removed, "artist passphrase", Recipient::Passphrase("gallery passphrase")).unwrap();
+      assert!(remove_recipient(&single, "artist passphrase", Recipient::Passphrase("artist passphrase")).is_err());
+    }
+
+    #[test]
+    fn test_add_recipient_to_single_passphrase() {
+      use crate::decryption::text::open_body;
+      use crate::encryption::envelope::Header;
+      use crate::encryption::recipients::{add_recipient, open, Recipient};
+      use crate::encryption::text::SuiteKeys;
+      use base64::{Engine as _, engine::general_purpose::STANDARD};
+      let input = "ThisIsJustaTestString";
+      for strength in [None, Some("advanced"), Some("authenticated")] {
+        let encrypted = encrypts(input, Some("first passphrase"), strength).unwrap();
+        let shared = add_recipient(&encrypted, "first passphrase", Recipient::Passphrase("second passphrase")).unwrap();
+        assert_eq!(decrypts(&shared, Some("first passphrase")).unwrap(), input);
+        assert_eq!(decrypts(&shared, Some("second passphrase")).unwrap(), input);
+
+        // The content key the new recipient unwraps doesn't open the original result
+        let (_, content_key, _) = open(&shared, "second passphrase", &[]).unwrap();
+        let bytes = STANDARD.decode(&encrypted).unwrap();
+        let (header, header_len) = Header::parse(&bytes).unwrap();
+        let keys = SuiteKeys::expand(&content_key, header.suite).unwrap();
+        assert!(open_body(header.suite, &keys, &bytes[..header_len], &bytes[header_len..]).is_err());
+      }
+    }
+
+    #[test]
+    fn test_signed_img() {
+      use crate::encryption::keys::{KeyPair, SigningKeyPair};
+      use crate::encryption::signing::{create_signed_img, sign_img};
+      use crate::decryption::verification::{verify_img, verify_img_signed_by};
+      let artist = SigningKeyPair::generate().unwrap();
+      let (signing_key, public_key) = (artist.private_key_pem().unwrap(), artist.public_key_pem().unwrap());
+      let ciphertext = encrypts("ThisIsJustaTestString", Some("your_secret_key"), Some("authenticated")).unwrap();
+      let signed = create_signed_img(&ciphertext, "h", "bitcoin", None, None, None, None, None, None, &signing_key).unwrap();
+      // The signature doesn't change what the image decodes to
+      assert_eq!(decode_image_and_extract_text(&signed).unwrap(), ciphertext.clone());
+      assert_eq!(verify_img(&signed).unwrap(), public_key.clone());
+      assert!(verify_img_signed_by(&signed, &public_key));
+
+      // A signature moved onto an image of another ciphertext doesn't verify
+      let other_ciphertext = encrypts("ThisIsAnotherTestString", Some("your_secret_key"), Some("authenticated")).unwrap();
+      let other = create_img(&other_ciphertext, "h", "bitcoin", None, None, None, None, None, None).unwrap();
+      assert!(verify_img(&other).is_err());
+      let forged = {
+        use base64::{Engine as _, engine::general_purpose::STANDARD};
+        let signed_png = STANDARD.decode(&signed).unwrap();
+        let other_png = STANDARD.decode(&other).unwrap();
+        let chunk_start = signed_png.windows(4).position(|window| window == b"tEXt").unwrap() - 4;
+        let chunk = &signed_png[chunk_start..signed_png.len() - 12];
+        let end = other_png.len() - 12;
+        STANDARD.encode([&other_png[..end], chunk, &other_png[end..]].concat())
+      };
+      assert!(verify_img(&forged).is_err());
+      // Only Ed25519 keys sign
+      assert!(sign_img(&other, &KeyPair::generate().unwrap().private_key_pem().unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_rekey_preserves_image_settings() {
+      use crate::encryption::images::ImageSettings;
+      use crate::encryption::rekey::rekey;
+      use base64::{Engine as _, engine::general_purpose::STANDARD};
+      let input = "ThisIsJustaTestString";
+      let ciphertext = encrypts(input, Some("leaked passphrase"), Some("authenticated")).unwrap();
+      let image = create_img(&ciphertext, "h2", "bitcoin", Some(10), Some(20), Some(30), None, None, None).unwrap();
+      let rekeyed = rekey(&image, Some("leaked passphrase"), Some("new passphrase")).unwrap();
+      assert_eq!(ImageSettings::from_img(&rekeyed).unwrap(), ImageSettings::from_img(&image).unwrap());
+      let rekeyed_ciphertext = decode_image_and_extract_text(&rekeyed).unwrap();
+      assert_eq!(decrypts(&rekeyed_ciphertext, Some("new passphrase")).unwrap(), input);
+      assert!(decrypts(&rekeyed_ciphertext, Some("leaked passphrase")).is_err());
+      assert!(rekey(&image, Some("wrong passphrase"), Some("new passphrase")).is_err());
+
+      // Images without stored settings are matched against the built-in watermarks
+      let png = STANDARD.decode(create_img(&ciphertext, "h", "cardano", None, None, None, None, None, None).unwrap()).unwrap();
+      let chunk_start = png.windows(4).position(|window| window == b"tEXt").unwrap() - 4;
+      let legacy = STANDARD.encode([&png[..chunk_start], &png[png.len() - 12..]].concat());
+      assert_eq!(ImageSettings::from_img(&legacy).unwrap().watermark, "cardano");
+      let rekeyed = rekey(&legacy, Some("leaked passphrase"), Some("new passphrase")).unwrap();
+      assert_eq!(ImageSettings::from_img(&rekeyed).unwrap().watermark, "cardano");
+    }
+
+    #[test]
+    fn test_rekey_legacy_and_recipients() {
+      use crate::Error;
+      use crate::decryption::text::open_body;
+      use crate::encryption::envelope::{read_header, Header, Suite};
+      use crate::encryption::keys::KeyPair;
+      use crate::encryption::recipients::{encrypts_for, open, Recipient};
+      use crate::encryption::rekey::rekey;
+      use crate::encryption::text::SuiteKeys;
+      use base64::{Engine as _, engine::general_purpose::STANDARD};
+      let legacy = "VkdocGN5QkpjeUJRYkE9PZNY2MOW01NWpSxCtFG6acHuAWun+CElPQ/IIwd0gy+D+IiBqB/5+qo8Jr9bMBOwoih3amCtjXlkAlRKHX5fhqI=";
+      let rekeyed = rekey(legacy, None, Some("new passphrase")).unwrap();
+      assert_eq!(read_header(&rekeyed).unwrap().suite, Suite::Aes256GcmSiv);
+      assert_eq!(decrypts(&rekeyed, Some("new passphrase")).unwrap(), "This Is Plain Text");
+
+      let gallery = KeyPair::generate().unwrap();
+      let gallery_public_key = gallery.public_key_pem().unwrap();
+      let encrypted = encrypts_for("ThisIsJustaTestString", &[Recipient::Passphrase("leaked passphrase"), Recipient::PublicKey(&gallery_public_key)]).unwrap();
+      let rekeyed = rekey(&encrypted, Some("leaked passphrase"), Some("new passphrase")).unwrap();
+      assert!(decrypts(&rekeyed, Some("leaked passphrase")).is_err());
+      assert_eq!(decrypts(&rekeyed, Some("new passphrase")).unwrap(), "ThisIsJustaTestString");
+      assert_eq!(decrypts(&rekeyed, Some(&gallery.private_key_pem().unwrap())).unwrap(), "ThisIsJustaTestString");
+
+      // The content key known to the holder of the leaked passphrase no longer opens the body
+      let (_, old_content_key, _) = open(&encrypted, "leaked passphrase", &[]).unwrap();
+      let bytes = STANDARD.decode(&rekeyed).unwrap();
+      let (header, header_len) = Header::parse(&bytes).unwrap();
+      let old_keys = SuiteKeys::expand(&old_content_key, header.suite).unwrap();
+      assert!(open_body(header.suite, &old_keys, &bytes[..header_len], &bytes[header_len..]).is_err());
+
+      let shared = encrypts_for("ThisIsJustaTestString", &[Recipient::Passphrase("leaked passphrase"), Recipient::Passphrase("gallery passphrase")]).unwrap();
+      assert!(matches!(rekey(&shared, Some("leaked passphrase"), Some("new passphrase")), Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_secret_shares() {
+      use crate::encryption::images::ImageSettings;
+      use crate::encryption::shares::{gf256, split_to_imgs};
+      use crate::decryption::shares::combine_imgs;
+      for a in 1..=255u8 {
+        assert_eq!(gf256::mul(a, gf256::inv(a)), 1);
+      }
+      let secret = "naïve 🦀 seed phrase";
+      let key = Some("your_secret_key");
+      let images = split_to_imgs(secret, 3, 5, key, &ImageSettings::default()).unwrap();
+      for (a, b, c) in [(0, 1, 2), (4, 2, 0), (1, 3, 4)] {
+        assert_eq!(combine_imgs(&[&images[a], &images[b], &images[c]], key).unwrap(), secret);
+      }
+      assert_eq!(combine_imgs(&images.iter().map(String::as_str).collect::<Vec<_>>(), key).unwrap(), secret);
+      assert!(combine_imgs(&[&images[0], &images[1]], key).is_err());
+      assert!(combine_imgs(&[&images[0], &images[1], &images[1]], key).is_err());
+      assert!(combine_imgs(&[&images[0], &images[1], &images[2]], Some("wrong_secret_key")).is_err());
+      // Shares of another split of the same secret don't mix
+      let other = split_to_imgs(secret, 3, 5, key, &ImageSettings::default()).unwrap();
+      assert!(combine_imgs(&[&images[0], &images[1], &other[2]], key).is_err());
+      assert!(split_to_imgs(secret, 4, 3, key, &ImageSettings::default()).is_err());
+    }
+
+    #[test]
+    fn test_associated_data() {
+      use crate::encryption::text::{encrypts_with, EncryptOptions};
+      use crate::decryption::text::{decrypts_with, DecryptOptions};
+      let input = "ThisIsJustaTestString";
+      let key = Some("your_secret_key");
+      let options = EncryptOptions { aad: b"token 42".to_vec(), ..EncryptOptions::default() };
+      let token = |aad: &[u8]| DecryptOptions { aad: aad.to_vec(), ..DecryptOptions::default() };
+      for strength in [None, Some("advanced"), Some("authenticated")] {
+        let encrypted = encrypts_with(input, key, strength, &options).unwrap();
+        assert_eq!(decrypts_with(&encrypted, key, &token(b"token 42")).unwrap(), input);
+        assert!(decrypts_with(&encrypted, key, &token(b"token 43")).is_err());
+        assert!(decrypts(&encrypted, key).is_err());
+        // Without associated data nothing changes
+        let plain = encrypts(input, key, strength).unwrap();
+        assert!(decrypts_with(&plain, key, &token(b"token 42")).is_err());
+      }
+      // The 1.x layout can't be bound to anything
+      let legacy = "VkdocGN5QkpjeUJRYkE9PZNY2MOW01NWpSxCtFG6acHuAWun+CElPQ/IIwd0gy+D+IiBqB/5+qo8Jr9bMBOwoih3amCtjXlkAlRKHX5fhqI=";
+      assert!(decrypts_with(legacy, None, &token(b"token 42")).is_err());
+    }
+
+    #[test]
+    fn test_stream_associated_data() {
+      use std::io::Write;
+      use crate::encryption::stream::StreamEncryptor;
+      use crate::encryption::text::EncryptOptions;
+      use crate::decryption::stream::StreamDecryptor;
+      use crate::decryption::text::DecryptOptions;
+      let key = Some("your_secret_key");
+      let options = EncryptOptions { aad: b"video.mp4".to_vec(), ..EncryptOptions::default() };
+      let mut encryptor = StreamEncryptor::with_options(Vec::new(), key, &options).unwrap();
+      encryptor.write_all(b"ThisIsJustaTestString").unwrap();
+      let encrypted = encryptor.finish().unwrap();
+      for (aad, valid) in [(&b"video.mp4"[..], true), (&b"other.mp4"[..], false), (&b""[..], false)] {
+        let mut decryptor = StreamDecryptor::with_options(Vec::new(), key, &DecryptOptions { aad: aad.to_vec(), ..DecryptOptions::default() });
+        let decrypted = decryptor.write_all(&encrypted).and_then(|_| decryptor.finish());
+        assert_eq!(decrypted.ok(), if valid { Some(b"ThisIsJustaTestString".to_vec()) } else { None });
+      }
+    }
+
+    #[test]
+    fn test_compression() {
+      use crate::encryption::compression::Compression;
+      use crate::encryption::envelope::{read_header, FLAG_DEFLATE};
+      use crate::encryption::recipients::{add_recipient, Recipient};
+      use crate::encryption::rekey::rekey;
+      use crate::encryption::text::{encrypts_with, EncryptOptions};
+      let key = Some("your_secret_key");
+      let input = "ThisIsJustaTestString".repeat(50);
+      let options = EncryptOptions { compression: Compression::Deflate, ..EncryptOptions::default() };
+      for strength in [None, Some("advanced"), Some("authenticated")] {
+        let compressed = encrypts_with(&input, key, strength, &options).unwrap();
+        assert_eq!(read_header(&compressed).unwrap().flags, FLAG_DEFLATE);
+        assert!(compressed.len() < encrypts(&input, key, strength).unwrap().len() / 4);
+        assert_eq!(decrypts(&compressed, key).unwrap(), input.clone());
+        // Re-encrypting keeps the plaintext compressed
+        let rekeyed = rekey(&compressed, key, Some("new_secret_key")).unwrap();
+        assert_eq!(decrypts(&rekeyed, Some("new_secret_key")).unwrap(), input.clone());
+        let shared = add_recipient(&compressed, "your_secret_key", Recipient::Passphrase("second passphrase")).unwrap();
+        assert_eq!(decrypts(&shared, Some("second passphrase")).unwrap(), input.clone());
+      }
+      // Input that doesn't get shorter is stored as it is
+      let short = encrypts_with("Hi", key, Some("authenticated"), &options).unwrap();
+      assert_eq!(read_header(&short).unwrap().flags, 0);
+      assert_eq!(decrypts(&short, key).unwrap(), "Hi");
+    }
+
+    #[test]
+    fn test_decompression_limit() {
+      use crate::encryption::compression::Compression;
+      use crate::encryption::text::{encrypt_bytes_with, EncryptOptions};
+      use crate::decryption::text::{decrypt_bytes, decrypt_bytes_with, DecryptOptions};
+      let key = Some("your_secret_key");
+      let options = EncryptOptions { compression: Compression::Deflate, ..EncryptOptions::default() };
+      let bomb = encrypt_bytes_with(&vec![0u8; 32 * 1024 * 1024], key, Some("authenticated"), &options).unwrap();
+      assert!(bomb.len() < 64 * 1024);
+      assert!(decrypt_bytes(&bomb, key).is_err());
+      let limit = |max_decompressed_len| DecryptOptions { max_decompressed_len, ..DecryptOptions::default() };
+      assert!(decrypt_bytes_with(&bomb, key, &limit(32 * 1024 * 1024 - 1)).is_err());
+      assert_eq!(decrypt_bytes_with(&bomb, key, &limit(32 * 1024 * 1024)).map(|plaintext| plaintext.len()).unwrap(), 32 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_metadata_validity_window() {
+      use crate::encryption::compression::Compression;
+      use crate::encryption::metadata::Metadata;
+      use crate::encryption::rekey::rekey;
+      use crate::encryption::text::{encrypts_with, EncryptOptions};
+      use crate::decryption::text::{decrypt_bytes_with_metadata, decrypts_with, DecryptOptions};
+      let key = Some("your_secret_key");
+      let metadata = Metadata { created: 1_000, not_before: Some(2_000), expires_at: Some(3_000) };
+      let at = |now| DecryptOptions { now: Some(now), ..DecryptOptions::default() };
+      for compression in [Compression::None, Compression::Deflate] {
+        let options = EncryptOptions { metadata: Some(metadata), compression, ..EncryptOptions::default() };
+        for strength in [None, Some("advanced"), Some("authenticated")] {
+          let encrypted = encrypts_with("ThisIsJustaTestString", key, strength, &options).unwrap();
+          assert!(decrypts_with(&encrypted, key, &at(1_999)).is_err());
+          assert_eq!(decrypts_with(&encrypted, key, &at(2_000)).unwrap(), "ThisIsJustaTestString");
+          assert_eq!(decrypts_with(&encrypted, key, &at(2_999)).unwrap(), "ThisIsJustaTestString");
+          assert!(decrypts_with(&encrypted, key, &at(3_000)).is_err());
+          // The system clock is far past the expiry
+          assert!(decrypts(&encrypted, key).is_err());
+          let (_, embedded) = decrypt_bytes_with_metadata(&encrypted, key, &at(2_500)).unwrap();
+          assert_eq!(embedded.unwrap(), metadata);
+          // Re-encrypting keeps the metadata
+          let rekeyed = rekey(&encrypted, key, Some("new_secret_key")).unwrap();
+          assert!(decrypts_with(&rekeyed, Some("new_secret_key"), &at(3_000)).is_err());
+          assert_eq!(decrypts_with(&rekeyed, Some("new_secret_key"), &at(2_500)).unwrap(), "ThisIsJustaTestString");
+        }
+      }
+      // Results without metadata report none and never expire
+      let plain = encrypts("ThisIsJustaTestString", key, None).unwrap();
+      assert!(decrypt_bytes_with_metadata(&plain, key, &at(u64::MAX)).unwrap().1.is_none());
+    }
+
+    #[test]
+    fn test_secret_key() {
+      use crate::encryption::keys::KeyPair;
+      use crate::encryption::secret::SecretKey;
+      use crate::encryption::text::encrypts_to;
+      let key = SecretKey::from(String::from("your_secret_key"));
+      assert_eq!(format!("{:?}", key), "SecretKey(<redacted>)");
+      assert_eq!(format!("{:?}", Some(key.clone())), "Some(SecretKey(<redacted>))");
+      for strength in [None, Some("advanced"), Some("authenticated")] {
+        let encrypted = encrypts("ThisIsJustaTestString", Some(&key), strength).unwrap();
+        assert_eq!(decrypts(&encrypted, Some("your_secret_key")).unwrap(), "ThisIsJustaTestString");
+      }
+      let legacy = "VkdocGN5QkpjeUJRYkE9PZNY2MOW01NWpSxCtFG6acHuAWun+CElPQ/IIwd0gy+D+IiBqB/5+qo8Jr9bMBOwoih3amCtjXlkAlRKHX5fhqI=";
+      assert!(decrypts(legacy, Some(&SecretKey::from("welovenfts"))).is_ok());
+      // Private keys are handed out as secrets too
+      let collector = KeyPair::generate().unwrap();
+      let private_key = collector.private_key_pem().unwrap();
+      assert_eq!(format!("{:?}", private_key), "SecretKey(<redacted>)");
+      let encrypted = encrypts_to("ThisIsJustaTestString", &collector.public_key_pem().unwrap()).unwrap();
+      assert_eq!(decrypts(&encrypted, Some(&private_key)).unwrap(), "ThisIsJustaTestString");
+    }
+
+    #[test]
+    fn test_keystore() {
+      use crate::encryption::keystore::Keystore;
+      let path = std::env::temp_dir().join(format!("encrypted_images_keystore_{}", std::process::id()));
+      let mut keystore = Keystore::create(&path, "master passphrase").unwrap();
+      assert!(Keystore::create(&path, "master passphrase").is_err());
+      let generated = keystore.generate("summer-drop").unwrap().clone();
+      assert_eq!(generated.len(), 44);
+      keystore.import("winter-drop", "your_secret_key").unwrap();
+      assert!(keystore.import("winter-drop", "another key").is_err());
+      assert!(keystore.generate("").is_err());
+      let encrypted = keystore.encrypts("summer-drop", "ThisIsJustaTestString", Some("authenticated")).unwrap();
+      assert!(keystore.encrypts("autumn-drop", "ThisIsJustaTestString", None).is_err());
+
+      // Reopening needs the master passphrase and restores every key
+      assert!(Keystore::open(&path, "wrong passphrase").is_err());
+      let mut keystore = Keystore::open(&path, "master passphrase").unwrap();
+      assert_eq!(keystore.list(), vec!["summer-drop", "winter-drop"]);
+      assert_eq!(keystore.export("summer-drop").unwrap(), generated.clone());
+      assert_eq!(keystore.decrypts("summer-drop", &encrypted).unwrap(), "ThisIsJustaTestString");
+      assert_eq!(decrypts(&encrypted, Some(&generated)).unwrap(), "ThisIsJustaTestString");
+      assert!(keystore.decrypts("winter-drop", &encrypted).is_err());
+      assert_eq!(keystore.delete("winter-drop").unwrap().expose(), "your_secret_key");
+      assert!(keystore.delete("winter-drop").is_err());
+      assert_eq!(Keystore::open(&path, "master passphrase").unwrap().list(), vec!["summer-drop"]);
+
+      // The file is an ordinary cipher, but only opens as a keystore
+      let contents = std::fs::read_to_string(&path).unwrap();
+      assert!(decrypts(&contents, Some("master passphrase")).is_err());
+      std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_error_variants() {
+      use crate::Error;
+      use crate::encryption::images::create_img;
+      use crate::encryption::metadata::Metadata;
+      use crate::encryption::text::{encrypts, encrypts_with, EncryptOptions};
+      use crate::decryption::text::{decrypts, decrypts_strict, decrypts_with, DecryptOptions};
+      use base64::{Engine as _, engine::general_purpose::STANDARD};
+
+      let key = Some("your_secret_key");
+      let encrypted = encrypts("ThisIsJustaTestString", key, Some("authenticated")).unwrap();
+      let mut bytes = STANDARD.decode(&encrypted).unwrap();
+      assert!(matches!(decrypts("not base64!", key), Err(Error::InvalidBase64)));
+      assert!(matches!(decrypts(&encrypted, Some("wrong_secret_key")), Err(Error::MacMismatch)));
+      // Header and key commitment, then 20 of the 12 + 21 + 16 bytes of nonce, ciphertext and tag
+      assert!(matches!(decrypts(&STANDARD.encode(&bytes[..bytes.len() - 29]), key), Err(Error::Truncated)));
+      assert!(matches!(decrypts(&STANDARD.encode(&bytes[..12]), key), Err(Error::Truncated)));
+      bytes[4] = 9;
+      assert!(matches!(decrypts(&STANDARD.encode(&bytes), key), Err(Error::UnsupportedFormat)));
+      assert!(matches!(decrypts(&STANDARD.encode([0u8; 10]), key), Err(Error::Truncated)));
+
+      let invalid_utf8 = crate::encryption::text::encrypt_bytes(&[0xff, 0xfe], key, Some("authenticated")).unwrap();
+      assert!(matches!(decrypts_strict(&invalid_utf8, key), Err(Error::InvalidUtf8)));
+
+      let metadata = Metadata { created: 1_000, not_before: Some(2_000), expires_at: Some(3_000) };
+      let ticket = encrypts_with("ThisIsJustaTestString", key, Some("authenticated"), &EncryptOptions { metadata: Some(metadata), ..EncryptOptions::default() }).unwrap();
+      let at = |now| DecryptOptions { now: Some(now), ..DecryptOptions::default() };
+      assert!(matches!(decrypts_with(&ticket, key, &at(1_500)), Err(Error::NotYetValid)));
+      assert!(matches!(decrypts_with(&ticket, key, &at(3_500)), Err(Error::Expired)));
+
+      let not_an_image = STANDARD.encode("not an image");
+      assert!(matches!(create_img(&encrypted, "h", &not_an_image, None, None, None, Some(0), Some(32), Some(32)), Err(Error::InvalidWatermark)));
+      assert!(matches!(create_img(&encrypted, "h", &not_an_image, None, None, None, None, None, None), Err(Error::InvalidWatermark)));
+      assert!(matches!(crate::decryption::images::decode_image_and_extract_text(&not_an_image), Err(Error::ImageDecode)));
+      assert!(Error::MacMismatch.to_string().contains("authentication"));
+    }
+
+    #[test]
+    fn test_untrusted_input_never_panics() {
+      use crate::Error;
+      use crate::encryption::envelope::{Header, KeyBlock, Slot, Suite};
+      use crate::encryption::images::{create_img, ImageSettings, MAX_IMAGE_DIMENSION};
+      use crate::encryption::kdf::{KdfParams, SALT_LEN};
+      use crate::encryption::keys::WRAPPED_KEY_LEN;
+      use crate::encryption::recipients::{encrypts_for, Recipient, MAX_PASSPHRASE_RECIPIENTS, MAX_RECIPIENTS};
+      use crate::encryption::text::{encrypts_with, EncryptOptions};
+      use crate::decryption::images::decode_image_and_extract_text;
+      use crate::decryption::text::{decrypts, decrypts_with, DecryptOptions};
+      use crate::decryption::verification::verify_img;
+      use base64::{Engine as _, engine::general_purpose::STANDARD};
+
+      let mut state = 0x2545_f491_u32;
+      let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state
+      };
+      let key = Some("your_secret_key");
+      let options = EncryptOptions { kdf: KdfParams { log_n: 1, ..KdfParams::default() }, ..EncryptOptions::default() };
+      let cipher = STANDARD.decode(encrypts_with("ThisIsJustaTestString", key, Some("authenticated"), &options).unwrap()).unwrap();
+      for length in 0..cipher.len() {
+        assert!(decrypts(&STANDARD.encode(&cipher[..length]), key).is_err());
+      }
+      for _ in 0..200 {
+        let mut flipped = cipher.clone();
+        let position = next() as usize % flipped.len();
+        flipped[position] ^= 1 << (next() % 8);
+        assert!(decrypts(&STANDARD.encode(&flipped), key).is_err());
+        let garbage: Vec<u8> = (0..next() % 128).map(|_| next() as u8).collect();
+        assert!(decrypts(&STANDARD.encode(&garbage), key).is_err());
+      }
+
+      let image = STANDARD.decode(create_img(&STANDARD.encode(&cipher), "h", "empty", None, None, None, None, None, None).unwrap()).unwrap();
+      for length in (0..image.len()).step_by(7) {
+        // A cut off image may still decode partially, it only must not panic
+        let truncated = STANDARD.encode(&image[..length]);
+        let _ = decode_image_and_extract_text(&truncated);
+        let _ = ImageSettings::from_img(&truncated);
+        assert!(verify_img(&truncated).is_err());
+      }
+
+      let mut huge = image.clone();
+      huge[16..24].copy_from_slice(&[0, 1, 0x86, 0xa0, 0, 1, 0x86, 0xa0]);
+      assert!(matches!(decode_image_and_extract_text(&STANDARD.encode(&huge)), Err(Error::TooLarge)));
+      let long_cipher = "A".repeat(MAX_IMAGE_DIMENSION as usize + 4);
+      assert!(matches!(create_img(&long_cipher, "h", "empty", None, None, None, None, None, None), Err(Error::TooLarge)));
+      let limited = DecryptOptions { max_input_len: 16, ..DecryptOptions::default() };
+      assert!(matches!(decrypts_with(&STANDARD.encode(&cipher), key, &limited), Err(Error::TooLarge)));
+
+      // Headers asking for the most scrypt work are refused before any key is derived
+      let body = &cipher[Header::parse(&cipher).unwrap().1..];
+      for kdf in [KdfParams { log_n: 20, r: 8, p: 255 }, KdfParams { log_n: 20, r: 8, p: 2 }, KdfParams { log_n: 1, r: 1, p: 17 }] {
+        let header = Header::new(Suite::Aes256Gcm, KeyBlock::Passphrase { kdf, salt: vec![0; SALT_LEN] });
+        assert!(matches!(decrypts(&STANDARD.encode([header.to_bytes(), body.to_vec()].concat()), key), Err(Error::UnsupportedFormat)));
+      }
+      let slot = Slot::Passphrase { kdf: KdfParams { log_n: 20, r: 8, p: 1 }, salt: vec![0; SALT_LEN], wrapped_key: vec![0; WRAPPED_KEY_LEN] };
+      let header = Header::new(Suite::Aes256Gcm, KeyBlock::Recipients(vec![slot; MAX_RECIPIENTS]));
+      assert!(matches!(decrypts(&STANDARD.encode([header.to_bytes(), body.to_vec()].concat()), key), Err(Error::UnsupportedFormat)));
+      let passphrases = vec![Recipient::Passphrase("your_secret_key"); MAX_PASSPHRASE_RECIPIENTS + 1];
+      assert!(matches!(encrypts_for("ThisIsJustaTestString", &passphrases), Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_key_commitment() {
+      use crate::Error;
+      use crate::encryption::envelope::Header;
+      use crate::encryption::text::{encrypts, SuiteKeys, COMMITMENT_LEN};
+      use crate::decryption::text::decrypts;
+      use base64::{Engine as _, engine::general_purpose::STANDARD};
+
+      for strength in [None, Some("authenticated")] {
+        let encrypted = encrypts("ThisIsJustaTestString", Some("first_key"), strength).unwrap();
+        let bytes = STANDARD.decode(&encrypted).unwrap();
+        let (header, header_len) = Header::parse(&bytes).unwrap();
+        let commitment = header_len..header_len + COMMITMENT_LEN;
+        assert_eq!(bytes[commitment.clone()], SuiteKeys::derive("first_key", &header).unwrap().commitment[..]);
+
+        // A body claiming to be committed to another key opens under neither
+        let mut recommitted = bytes.clone();
+        recommitted[commitment.clone()].copy_from_slice(&SuiteKeys::derive("second_key", &header).unwrap().commitment);
+        let recommitted = STANDARD.encode(&recommitted);
+        assert!(matches!(decrypts(&recommitted, Some("second_key")), Err(Error::MacMismatch)));
+        assert!(matches!(decrypts(&recommitted, Some("first_key")), Err(Error::MacMismatch)));
+      }
+    }
+
+    #[test]
+    fn test_padding() {
+      use crate::Error;
+      use crate::encryption::compression::Compression;
+      use crate::encryption::images::create_img;
+      use crate::encryption::padding::Padding;
+      use crate::encryption::rekey::rekey;
+      use crate::encryption::text::{encrypts_with, EncryptOptions};
+      use crate::decryption::text::decrypts;
+      use base64::{Engine as _, engine::general_purpose::STANDARD};
+      use image::GenericImageView;
+
+      let key = Some("your_secret_key");
+      // 19 to 25 bytes, the same size once padded under every policy
+      let inputs = ["Yes, this is a test", "ThisIsJustaTestString", "ThisIsJust🦀aTestString"];
+      for padding in [Padding::Bucket(32), Padding::PowerOfTwo, Padding::Fixed(40)] {
+        for strength in [None, Some("advanced"), Some("authenticated")] {
+          let options = EncryptOptions { padding, ..EncryptOptions::default() };
+          let encrypted: Vec<String> = inputs.iter().map(|input| encrypts_with(input, key, strength, &options).unwrap()).collect();
+          for (input, cipher) in inputs.iter().zip(&encrypted) {
+            assert_eq!(cipher.len(), encrypted[0].len());
+            assert_eq!(decrypts(cipher, key).unwrap(), *input);
+          }
+          let images: Vec<String> = encrypted.iter().map(|cipher| create_img(cipher, "h", "empty", None, None, None, None, None, None).unwrap()).collect();
+          assert!(images.iter().all(|image| image::load_from_memory(&STANDARD.decode(image).unwrap()).unwrap().width() == encrypted[0].len() as u32));
+        }
+      }
+
+      let compressed = EncryptOptions { compression: Compression::Deflate, padding: Padding::PowerOfTwo, ..EncryptOptions::default() };
+      let input = "ThisIsJustaTestString".repeat(20);
+      let encrypted = encrypts_with(&input, key, Some("authenticated"), &compressed).unwrap();
+      assert_eq!(decrypts(&rekey(&encrypted, key, Some("new_key")).unwrap(), Some("new_key")).unwrap(), input);
+
+      let too_short = EncryptOptions { padding: Padding::Fixed(8), ..EncryptOptions::default() };
+      assert!(matches!(encrypts_with("ThisIsJustaTestString", key, None, &too_short), Err(Error::InvalidArgument(_))));
+      let empty_bucket = EncryptOptions { padding: Padding::Bucket(0), ..EncryptOptions::default() };
+      assert!(matches!(encrypts_with("ThisIsJustaTestString", key, None, &empty_bucket), Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_deterministic_known_answer() {
+      // The same under every backend, so either one opens what the other encrypted.
+      let encrypted = encrypts("ThisIsJustaTestString", Some("your_secret_key"), None).unwrap();
+      assert_eq!(encrypted, "4UlNRwIDAAEAEw8IAWVuY3J5cHRlZF9pbWFnZXOOXvdn9PjftxvWAFuGn0OHufOHFXDjykslQUyMl3PnKCOkNpH58msUub9SWED7gGHI4rWPbXPTAD4TqdJXeF7QGHt0lTQ=");
+      assert_eq!(decrypts(&encrypted, Some("your_secret_key")).unwrap(), "ThisIsJustaTestString");
+    }
+
+    #[cfg(all(feature = "openssl", feature = "rust-crypto"))]
+    #[test]
+    fn test_backends_agree() {
+      use crate::Error;
+      use crate::backend::{Backend, Openssl, RustCrypto};
+      use crate::encryption::kdf::KdfParams;
+      use crate::encryption::keys::{KeyPair, SigningKeyPair};
+      use openssl::pkey::{Id, PKey};
+
+      let key = [7u8; 32];
+      let nonce = [9u8; 12];
+      let (aad, data) = (b"associated".as_slice(), b"ThisIsJustaTestString".as_slice());
+      let sealed = Openssl::aes_256_gcm_seal(&key, &nonce, aad, data).unwrap();
+      assert_eq!(RustCrypto::aes_256_gcm_seal(&key, &nonce, aad, data).unwrap(), sealed);
+      assert_eq!(RustCrypto::aes_256_gcm_open(&key, &nonce, aad, &sealed).unwrap(), data);
+      assert!(matches!(RustCrypto::aes_256_gcm_open(&key, &nonce, b"other", &sealed), Err(Error::MacMismatch)));
+      let sealed = Openssl::aes_256_gcm_siv_seal(&key, &nonce, aad, data).unwrap();
+      assert_eq!(RustCrypto::aes_256_gcm_siv_seal(&key, &nonce, aad, data).unwrap(), sealed);
+      assert_eq!(RustCrypto::aes_256_gcm_siv_open(&key, &nonce, aad, &sealed).unwrap(), data);
+      assert!(matches!(Openssl::aes_256_gcm_siv_open(&key, &nonce, b"other", &sealed), Err(Error::MacMismatch)));
+      let iv = [3u8; 16];
+      let encrypted = Openssl::aes_128_cbc_encrypt(&key[..16], &iv, data).unwrap();
+      assert_eq!(RustCrypto::aes_128_cbc_encrypt(&key[..16], &iv, data).unwrap(), encrypted);
+      assert_eq!(RustCrypto::aes_128_cbc_decrypt(&key[..16], &iv, &encrypted).unwrap(), data);
+      assert_eq!(RustCrypto::hmac_sha256(&key, &[aad, data]).unwrap(), Openssl::hmac_sha256(&key, &[aad, data]).unwrap());
+      assert_eq!(*RustCrypto::hkdf_sha256(&key, aad, 48).unwrap(), *Openssl::hkdf_sha256(&key, aad, 48).unwrap());
+      let params = KdfParams { log_n: 10, r: 8, p: 1 };
+      let (mut ours, mut theirs) = ([0u8; 32], [0u8; 32]);
+      RustCrypto::scrypt(data, aad, &params, &mut ours).unwrap();
+      Openssl::scrypt(data, aad, &params, &mut theirs).unwrap();
+      assert_eq!(ours, theirs);
+
+      let public_key = RustCrypto::x25519_public_key(&key).unwrap();
+      assert_eq!(Openssl::x25519_public_key(&key).unwrap(), public_key);
+      assert_eq!(*RustCrypto::x25519(&nonce.repeat(3)[..32], &public_key).unwrap(), *Openssl::x25519(&nonce.repeat(3)[..32], &public_key).unwrap());
+      assert!(matches!(RustCrypto::x25519(&key, &[0u8; 32]), Err(Error::InvalidKey)));
+      assert!(matches!(Openssl::x25519(&key, &[0u8; 32]), Err(Error::InvalidKey)));
+      let public_key = RustCrypto::ed25519_public_key(&key).unwrap();
+      assert_eq!(Openssl::ed25519_public_key(&key).unwrap(), public_key);
+      let signature = RustCrypto::ed25519_sign(&key, data).unwrap();
+      assert_eq!(Openssl::ed25519_sign(&key, data).unwrap(), signature);
+      assert!(Openssl::ed25519_verify(&public_key, data, &signature) && RustCrypto::ed25519_verify(&public_key, data, &signature));
+      assert!(!Openssl::ed25519_verify(&public_key, aad, &signature) && !RustCrypto::ed25519_verify(&public_key, aad, &signature));
+
+      // The PEM the crate writes is what OpenSSL writes for the same key.
+      let pair = KeyPair::generate().unwrap();
+      let private_key = PKey::private_key_from_pem(pair.private_key_pem().unwrap().as_bytes()).unwrap();
+      assert_eq!(private_key.id(), Id::X25519);
+      assert_eq!(String::from_utf8(private_key.private_key_to_pem_pkcs8().unwrap()).unwrap(), *pair.private_key_pem().unwrap());
+      assert_eq!(String::from_utf8(private_key.public_key_to_pem().unwrap()).unwrap(), pair.public_key_pem().unwrap());
+      let pair = SigningKeyPair::generate().unwrap();
+      let private_key = PKey::private_key_from_pem(pair.private_key_pem().unwrap().as_bytes()).unwrap();
+      assert_eq!(private_key.id(), Id::ED25519);
+      assert_eq!(String::from_utf8(private_key.public_key_to_pem().unwrap()).unwrap(), pair.public_key_pem().unwrap());
+    }
+
+    #[test]
+    fn test_deniable() {
+      use crate::Error;
+      use crate::encryption::compression::Compression;
+      use crate::encryption::deniable::{encrypts_deniable, encrypts_deniable_with, Decoy};
+      use crate::decryption::text::{decrypts_with, DecryptOptions};
+      use crate::encryption::envelope::{read_header, Suite};
+      use crate::encryption::metadata::Metadata;
+      use crate::encryption::padding::Padding;
+      use crate::encryption::rekey::rekey;
+      use crate::encryption::text::{encrypts_with, EncryptOptions};
+
+      let decoy = Decoy { input: "Meet me at the gallery", key: "holiday photos" };
+      let encrypted = encrypts_deniable("ThisIsJustaTestString", "real passphrase", decoy).unwrap();
+      let image = create_img(&encrypted, "h", "empty", None, None, None, None, None, None).unwrap();
+      let ciphertext = decode_image_and_extract_text(&image).unwrap();
+      assert_eq!(decrypts(&ciphertext, Some("real passphrase")).unwrap(), "ThisIsJustaTestString");
+      assert_eq!(decrypts(&ciphertext, Some("holiday photos")).unwrap(), "Meet me at the gallery");
+      assert!(matches!(decrypts(&encrypted, Some("wrong passphrase")), Err(Error::MacMismatch)));
+      let same_key = Decoy { input: "Meet me at the gallery", key: "real passphrase" };
+      assert!(matches!(encrypts_deniable("ThisIsJustaTestString", "real passphrase", same_key), Err(Error::InvalidArgument(_))));
+
+      // Looks like an ordinary padded result of the same size
+      let fixed = EncryptOptions { padding: Padding::Fixed(256), ..EncryptOptions::default() };
+      let deniable = encrypts_deniable_with("ThisIsJustaTestString", "real passphrase", decoy, &fixed).unwrap();
+      let ordinary = encrypts_with("Meet me at the gallery", Some("holiday photos"), Some("authenticated"), &fixed).unwrap();
+      assert_eq!(deniable.len(), ordinary.len());
+      assert_eq!(read_header(&deniable).unwrap().suite, Suite::Aes256Gcm);
+      assert_eq!(read_header(&deniable).unwrap().flags, read_header(&ordinary).unwrap().flags);
+      assert_eq!(decrypts(&deniable, Some("real passphrase")).unwrap(), "ThisIsJustaTestString");
+      let odd = EncryptOptions { padding: Padding::Fixed(255), ..EncryptOptions::default() };
+      assert!(matches!(encrypts_deniable_with("ThisIsJustaTestString", "real passphrase", decoy, &odd), Err(Error::InvalidArgument(_))));
+
+      // Rekeying keeps the message of the passphrase used
+      assert_eq!(decrypts(&rekey(&encrypted, Some("holiday photos"), Some("new passphrase")).unwrap(), Some("new passphrase")).unwrap(), "Meet me at the gallery");
+      assert_eq!(decrypts(&rekey(&encrypted, Some("real passphrase"), Some("new passphrase")).unwrap(), Some("new passphrase")).unwrap(), "ThisIsJustaTestString");
+
+      let options = EncryptOptions { compression: Compression::Deflate, padding: Padding::Bucket(63), metadata: Some(Metadata::now()), aad: b"token 42".to_vec(), ..EncryptOptions::default() };
+      let encrypted = encrypts_deniable_with(&"ThisIsJustaTestString".repeat(10), "real passphrase", decoy, &options).unwrap();
+      let token_42 = DecryptOptions { aad: b"token 42".to_vec(), ..DecryptOptions::default() };
+      assert_eq!(decrypts_with(&encrypted, Some("real passphrase"), &token_42).unwrap(), "ThisIsJustaTestString".repeat(10));
+      assert_eq!(decrypts_with(&encrypted, Some("holiday photos"), &token_42).unwrap(), "Meet me at the gallery");
+    }
+
+    #[test]
+    fn test_security_policy() {
+      use crate::Error;
+      use crate::encryption::envelope::read_header;
+      use crate::encryption::kdf::KdfParams;
+      use crate::encryption::policy::{estimate_entropy, SecurityPolicy};
+      use crate::encryption::recipients::{encrypts_for, Recipient};
+      use crate::encryption::text::{encrypts_with, EncryptOptions};
+
+      // Installing a policy would affect the tests running in parallel, the doctests install one.
+      let strict = SecurityPolicy::strict();
+      let strong = "correct horse battery staple";
+      assert!(matches!(strict.check_passphrase("welovenfts"), Err(Error::PolicyViolation("default key"))));
+      assert!(matches!(strict.check_passphrase("your_key"), Err(Error::PolicyViolation(_))));
+      assert!(strict.check_passphrase(strong).is_ok());
+      assert!(SecurityPolicy::default().check_passphrase("welovenfts").is_ok());
+      assert!(estimate_entropy("abc") < estimate_entropy("aB3") && estimate_entropy("aB3") < estimate_entropy("aB3!"));
+
+      let input = "ThisIsJustaTestString";
+      let header = |encrypted: &str| read_header(encrypted).unwrap();
+      let authenticated = encrypts(input, Some(strong), Some("authenticated")).unwrap();
+      assert!(strict.check_header(Some(&header(&authenticated))).is_ok());
+      let deterministic = encrypts(input, Some(strong), None).unwrap();
+      assert!(matches!(strict.check_header(Some(&header(&deterministic))), Err(Error::PolicyViolation(_))));
+      let only_deterministic_forbidden = SecurityPolicy { forbid_deterministic: true, ..SecurityPolicy::default() };
+      assert!(matches!(only_deterministic_forbidden.check_header(Some(&header(&deterministic))), Err(Error::PolicyViolation("deterministic encryption"))));
+      let advanced = encrypts(input, Some(strong), Some("advanced")).unwrap();
+      assert!(matches!(strict.check_header(Some(&header(&advanced))), Err(Error::PolicyViolation("cipher suite"))));
+      assert!(matches!(strict.check_header(None), Err(Error::PolicyViolation(_))));
+      let cheap = EncryptOptions { kdf: KdfParams { log_n: 10, ..KdfParams::default() }, ..EncryptOptions::default() };
+      let cheap = encrypts_with(input, Some(strong), Some("authenticated"), &cheap).unwrap();
+      assert!(matches!(strict.check_header(Some(&header(&cheap))), Err(Error::PolicyViolation("key derivation cost"))));
+      let shared = encrypts_for(input, &[Recipient::Passphrase(strong)]).unwrap();
+      assert!(strict.check_header(Some(&header(&shared))).is_ok());
+      assert_eq!(Error::PolicyViolation("default key").to_string(), "forbidden by the security policy: default key");
+    }
+
+    #[test]
+    fn test_recipients_associated_data() {
+      use crate::Error;
+      use crate::encryption::recipients::{add_recipient, add_recipient_with, encrypts_for_with, remove_recipient_with, Recipient};
+      use crate::encryption::text::{encrypts_with, EncryptOptions};
+      use crate::decryption::text::{decrypts_with, DecryptOptions};
+
+      let input = "ThisIsJustaTestString";
+      let token_42 = DecryptOptions { aad: b"token 42".to_vec(), ..DecryptOptions::default() };
+      let options = EncryptOptions { aad: b"token 42".to_vec(), ..EncryptOptions::default() };
+      let encrypted = encrypts_with(input, Some("first passphrase"), Some("authenticated"), &options).unwrap();
+      assert!(matches!(add_recipient(&encrypted, "first passphrase", Recipient::Passphrase("second passphrase")), Err(Error::MacMismatch)));
+      let added = add_recipient_with(&encrypted, "first passphrase", Recipient::Passphrase("second passphrase"), b"token 42").unwrap();
+      assert_eq!(decrypts_with(&added, Some("second passphrase"), &token_42).unwrap(), input);
+      assert!(decrypts(&added, Some("second passphrase")).is_err());
+      let removed = remove_recipient_with(&added, "second passphrase", Recipient::Passphrase("first passphrase"), b"token 42").unwrap();
+      assert_eq!(decrypts_with(&removed, Some("second passphrase"), &token_42).unwrap(), input);
+      assert!(decrypts_with(&removed, Some("first passphrase"), &token_42).is_err());
+
+      let shared = encrypts_for_with(input, &[Recipient::Passphrase("first passphrase")], b"token 42").unwrap();
+      assert_eq!(decrypts_with(&shared, Some("first passphrase"), &token_42).unwrap(), input);
+      assert!(matches!(decrypts(&shared, Some("first passphrase")), Err(Error::MacMismatch)));
+    }
+
+    #[test]
+    fn test_rekey_associated_data() {
+      use crate::Error;
+      use crate::encryption::images::create_img;
+      use crate::encryption::keys::KeyPair;
+      use crate::encryption::recipients::{add_recipient_with, Recipient};
+      use crate::encryption::rekey::{rekey, rekey_with};
+      use crate::encryption::text::{encrypts_with, EncryptOptions};
+      use crate::decryption::text::{decrypts_with, DecryptOptions};
+
+      let input = "ThisIsJustaTestString";
+      let token_42 = DecryptOptions { aad: b"token 42".to_vec(), ..DecryptOptions::default() };
+      let options = EncryptOptions { aad: b"token 42".to_vec(), ..EncryptOptions::default() };
+      for strength in [None, Some("advanced"), Some("authenticated")] {
+        let encrypted = encrypts_with(input, Some("leaked passphrase"), strength, &options).unwrap();
+        assert!(matches!(rekey(&encrypted, Some("leaked passphrase"), Some("new passphrase")), Err(Error::MacMismatch)));
+        let rekeyed = rekey_with(&encrypted, Some("leaked passphrase"), Some("new passphrase"), b"token 42").unwrap();
+        assert_eq!(decrypts_with(&rekeyed, Some("new passphrase"), &token_42).unwrap(), input);
+        assert!(decrypts(&rekeyed, Some("new passphrase")).is_err());
+      }
+
+      let encrypted = encrypts_with(input, Some("leaked passphrase"), Some("authenticated"), &options).unwrap();
+      let gallery = KeyPair::generate().unwrap();
+      let shared = add_recipient_with(&encrypted, "leaked passphrase", Recipient::PublicKey(&gallery.public_key_pem().unwrap()), b"token 42").unwrap();
+      let rekeyed = rekey_with(&shared, Some("leaked passphrase"), Some("new passphrase"), b"token 42").unwrap();
+      assert_eq!(decrypts_with(&rekeyed, Some("new passphrase"), &token_42).unwrap(), input);
+      assert_eq!(decrypts_with(&rekeyed, Some(&gallery.private_key_pem().unwrap()), &token_42).unwrap(), input);
+
+      let image = create_img(&encrypted, "h", "empty", None, None, None, None, None, None).unwrap();
+      let rekeyed = rekey_with(&image, Some("leaked passphrase"), Some("new passphrase"), b"token 42").unwrap();
+      assert_eq!(decrypts_with(&decode_image_and_extract_text(&rekeyed).unwrap(), Some("new passphrase"), &token_42).unwrap(), input);
+      let legacy = "VkdocGMybHpiWGxqYnc9PbUWoPUFfy9Izm1wkCFZ8gSMWr6EUGW6UwYpnaounDkYmLNDjqWyvjcus2atCStKBOJSCnosjApRrcJrm44hatuaJHSYONbHNOmpk3Rja/xH";
+      assert!(matches!(rekey_with(legacy, None, Some("new passphrase"), b"token 42"), Err(Error::UnsupportedFormat)));
     }
   }